@@ -0,0 +1,807 @@
+use std::f64::consts::{PI, TAU};
+
+use crate::dimension::Dimension;
+use crate::error::UnitError;
+use crate::format::ValueFormat;
+use crate::parse::parse_quantity_as;
+use crate::units::*;
+use crate::values::{AngleValue, Value};
+
+/// BearingValue struct
+///
+/// A direction on the compass, stored in Radians clockwise from north
+/// and always normalised to [0, 2π), so 370° is stored as 10°.
+///
+/// Each bearing is tagged with the north it is measured from, true north
+/// unless tagged otherwise with `with_reference`. Bearings from different
+/// norths only compare after `to_reference` converts one of them.
+///
+/// The difference between two directions is an `AngleValue`, and the
+/// arithmetic wraps around north:
+/// - BearingValue - BearingValue = AngleValue, the shortest turn in (-180°, 180°]
+/// - BearingValue ± AngleValue = BearingValue
+///
+/// # Example
+/// ```rust
+/// use units_and_values::bearing::*;
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let heading = BearingValue::new(350.0, &BearingUnit::Degrees);
+/// let turned = heading + AngleValue::new(30.0, &BearingUnit::Degrees);
+/// assert!((turned.value_in(&BearingUnit::Degrees) - 20.0).abs() < 1e-9);
+/// assert!(((turned - heading).value_in(&BearingUnit::Degrees) - 30.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct BearingValue {
+    /// value in Radians, in [0, 2π)
+    value: f64,
+    /// north the value is measured from
+    reference: BearingReference,
+}
+
+/// The north a bearing is measured from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
+pub enum BearingReference {
+    /// the geographic north pole, as on charts
+    #[default]
+    True,
+    /// the direction of the Earth's magnetic field, as on a compass
+    Magnetic,
+    /// the north of the grid of a map projection, such as UTM
+    Grid,
+}
+
+/// Implementing Display trait for BearingReference
+impl std::fmt::Display for BearingReference {
+    /// Display the reference name
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::True => write!(f, "True"),
+            Self::Magnetic => write!(f, "Magnetic"),
+            Self::Grid => write!(f, "Grid"),
+        }
+    }
+}
+
+/// NorthOffsets struct
+///
+/// The angles from true north to magnetic north and to grid north at a place,
+/// both positive to the east, used to convert bearings between references.
+///
+/// The declination can come from `magnetic::MagneticModel`; the grid convergence
+/// depends on the map projection and comes from the map or its projection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NorthOffsets {
+    /// angle from true north to magnetic north, positive to the east
+    pub declination: AngleValue,
+    /// angle from true north to grid north, positive to the east
+    pub grid_convergence: AngleValue,
+}
+
+impl NorthOffsets {
+    /// Create a new NorthOffsets from the declination and the grid convergence.
+    pub fn new(declination: AngleValue, grid_convergence: AngleValue) -> NorthOffsets {
+        NorthOffsets { declination, grid_convergence }
+    }
+
+    /// Get the angle from true north to the north of `reference`.
+    fn offset_of(&self, reference: BearingReference) -> f64 {
+        match reference {
+            BearingReference::True => 0.0,
+            BearingReference::Magnetic => self.declination.si_value(),
+            BearingReference::Grid => self.grid_convergence.si_value(),
+        }
+    }
+}
+
+/// The side to turn to, to face another bearing by the shortest turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnDirection {
+    /// anticlockwise
+    Left,
+    /// clockwise, including a turn of exactly 180°
+    Right,
+    /// the bearings are the same
+    None,
+}
+
+/// How finely to divide the compass when naming a bearing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompassPoints {
+    /// N, E, S, W
+    Four,
+    /// N, NE, E, ...
+    Eight,
+    /// N, NNE, NE, ENE, ...
+    Sixteen,
+    /// N, N by E, NNE, NE by N, ...
+    ThirtyTwo,
+}
+
+impl CompassPoints {
+    /// Get the number of points on the compass.
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Four => 4,
+            Self::Eight => 8,
+            Self::Sixteen => 16,
+            Self::ThirtyTwo => 32,
+        }
+    }
+}
+
+/// Names of the 32 points of the compass, clockwise from north.
+/// Every second name is one of the 16 points, every fourth one of the 8, and so on.
+const COMPASS_POINTS: [&str; 32] = [
+    "N", "N by E", "NNE", "NE by N", "NE", "NE by E", "ENE", "E by N",
+    "E", "E by S", "ESE", "SE by E", "SE", "SE by S", "SSE", "S by E",
+    "S", "S by W", "SSW", "SW by S", "SW", "SW by W", "WSW", "W by S",
+    "W", "W by N", "WNW", "NW by W", "NW", "NW by N", "NNW", "N by W",
+];
+
+/// Reduce a compass point name to upper case letters with "by" as "B",
+/// so "NE by N", "NEbN" and "northeast by north" all become "NEBN".
+fn normalize_compass_point(input: &str) -> String {
+    let mut name: String = input
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .collect::<String>()
+        .to_uppercase();
+    for (word, letter) in [("NORTH", "N"), ("EAST", "E"), ("SOUTH", "S"), ("WEST", "W"), ("BY", "B")] {
+        name = name.replace(word, letter);
+    }
+    name
+}
+
+/// Split a number with its trailing symbol from the start of a DMS string.
+/// Example: "30'15\"" -> (30, '\'', "15\"")
+fn split_dms_part(input: &str) -> Option<(f64, char, &str)> {
+    let input = input.trim_start();
+    let end = input.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+    let value = input[..end].parse().ok()?;
+    let rest = input[end..].trim_start();
+    let symbol = rest.chars().next()?;
+    Some((value, symbol, &rest[symbol.len_utf8()..]))
+}
+
+/// Wrap an angle in Radians to [0, 2π).
+fn wrap_positive(radians: f64) -> f64 {
+    let wrapped = radians.rem_euclid(TAU);
+    // tiny negative angles round up to 2π itself
+    if wrapped >= TAU { 0.0 } else { wrapped }
+}
+
+/// Wrap an angle in Radians to (-π, π].
+fn wrap_signed(radians: f64) -> f64 {
+    let wrapped = wrap_positive(radians);
+    if wrapped > PI { wrapped - TAU } else { wrapped }
+}
+
+impl BearingValue {
+    /// Parse a bearing written in any of the formats of this module:
+    /// a point of the compass ("NE by N"), degrees, minutes and seconds ("45°30'15\""),
+    /// a heading of bare digits ("045"), or a number followed by an angle unit
+    /// (see `parse::parse_quantity`).
+    ///
+    /// Returns an error if the text does not parse,
+    /// or if the unit is not an angle unit.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let bearing = BearingValue::parse("-90 deg").unwrap();
+    /// assert_eq!(bearing.value_in(&BearingUnit::Degrees), 270.0);
+    /// ```
+    pub fn parse(input: &str) -> Result<BearingValue, UnitError> {
+        if let Ok(bearing) = BearingValue::parse_compass_point(input) {
+            return Ok(bearing);
+        }
+        if input.contains(['\'', '′', '"', '″']) {
+            return BearingValue::parse_dms(input);
+        }
+        let trimmed = input.trim();
+        if !trimmed.is_empty() && trimmed.chars().all(|c| c.is_ascii_digit()) {
+            return BearingValue::parse_heading(trimmed);
+        }
+        let parsed = parse_quantity_as(Dimension::ANGLE, input)?;
+        Ok(BearingValue::from_si_value(parsed.quantity().si_value()))
+    }
+
+    /// Get the bearing in the given unit, normalised to (-180°, 180°]
+    /// rather than [0°, 360°).
+    /// Example: 270° -> -90°
+    ///
+    /// Returns an error if the unit does not measure angles.
+    pub fn try_value_in_signed(&self, unit: &BearingUnit) -> Result<f64, UnitError> {
+        unit.try_convert(wrap_signed(self.value), &Self::unit_default(), &unit.unit_enum())
+    }
+
+    /// Get the bearing in the given unit, normalised to (-180°, 180°].
+    /// Panicking wrapper around `try_value_in_signed`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let west = BearingValue::new(270.0, &BearingUnit::Degrees);
+    /// assert_eq!(west.value_in_signed(&BearingUnit::Degrees), -90.0);
+    /// ```
+    pub fn value_in_signed(&self, unit: &BearingUnit) -> f64 {
+        self.try_value_in_signed(unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Get the north the bearing is measured from.
+    pub fn reference(&self) -> BearingReference {
+        self.reference
+    }
+
+    /// Tag the bearing as measured from the given north, keeping its value.
+    /// Use `to_reference` to convert the value to another north instead.
+    /// Example: a compass reading is `with_reference(BearingReference::Magnetic)`
+    pub fn with_reference(&self, reference: BearingReference) -> BearingValue {
+        BearingValue { value: self.value, reference }
+    }
+
+    /// Convert the bearing to one measured from another north.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// // 10° of east declination
+    /// let offsets = NorthOffsets::new(AngleValue::new(10.0, &BearingUnit::Degrees), AngleValue::from_si_value(0.0));
+    /// let compass = BearingValue::new(85.0, &BearingUnit::Degrees).with_reference(BearingReference::Magnetic);
+    /// let chart = compass.to_reference(BearingReference::True, &offsets);
+    /// assert!((chart.value_in(&BearingUnit::Degrees) - 95.0).abs() < 1e-9);
+    /// assert_eq!(chart.reference(), BearingReference::True);
+    /// ```
+    pub fn to_reference(&self, reference: BearingReference, offsets: &NorthOffsets) -> BearingValue {
+        let true_bearing = self.value + offsets.offset_of(self.reference);
+        BearingValue {
+            value: wrap_positive(true_bearing - offsets.offset_of(reference)),
+            reference,
+        }
+    }
+
+    /// Get the bearing turned clockwise by `radians`, from the same north.
+    fn rotated(&self, radians: f64) -> BearingValue {
+        BearingValue {
+            value: wrap_positive(self.value + radians),
+            reference: self.reference,
+        }
+    }
+
+    /// Get the opposite direction.
+    /// Example: 045° -> 225°
+    pub fn reciprocal(&self) -> BearingValue {
+        self.rotated(PI)
+    }
+
+    /// Get the shortest turn from self to `target`, in (-180°, 180°].
+    /// Positive turns are clockwise (to the right), negative turns are anticlockwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let heading = BearingValue::new(10.0, &BearingUnit::Degrees);
+    /// let target = BearingValue::new(340.0, &BearingUnit::Degrees);
+    /// let turn = heading.turn_to(&target);
+    /// assert!((turn.value_in(&BearingUnit::Degrees) + 30.0).abs() < 1e-9);
+    /// assert_eq!(heading.turn_direction_to(&target), TurnDirection::Left);
+    /// ```
+    pub fn turn_to(&self, target: &BearingValue) -> AngleValue {
+        self.try_turn_to(target)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Get the shortest turn from self to `target`, in (-180°, 180°].
+    ///
+    /// Returns an error if the bearings are measured from different norths.
+    pub fn try_turn_to(&self, target: &BearingValue) -> Result<AngleValue, UnitError> {
+        if target.reference != self.reference {
+            return Err(UnitError::ReferenceMismatch { expected: self.reference, found: target.reference });
+        }
+        Ok(AngleValue::from_si_value(wrap_signed(target.value - self.value)))
+    }
+
+    /// Get the side of the shortest turn from self to `target`.
+    /// Panicking wrapper around `try_turn_direction_to`.
+    pub fn turn_direction_to(&self, target: &BearingValue) -> TurnDirection {
+        self.try_turn_direction_to(target)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Get the side of the shortest turn from self to `target`.
+    ///
+    /// Returns an error if the bearings are measured from different norths.
+    pub fn try_turn_direction_to(&self, target: &BearingValue) -> Result<TurnDirection, UnitError> {
+        let turn = self.try_turn_to(target)?.si_value();
+        Ok(if turn > 0.0 {
+            TurnDirection::Right
+        } else if turn < 0.0 {
+            TurnDirection::Left
+        } else {
+            TurnDirection::None
+        })
+    }
+
+    /// Get the circular mean of a set of bearings,
+    /// the direction of the sum of their unit vectors.
+    /// The mean of 350° and 10° is 0°, not 180°.
+    ///
+    /// Returns None if there are no bearings, if they are measured from different norths,
+    /// or if they cancel out and have no mean direction, such as 0° and 180°.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let bearings = [
+    ///     BearingValue::new(350.0, &BearingUnit::Degrees),
+    ///     BearingValue::new(20.0, &BearingUnit::Degrees),
+    /// ];
+    /// let mean = BearingValue::circular_mean(&bearings).unwrap();
+    /// assert!((mean.value_in(&BearingUnit::Degrees) - 5.0).abs() < 1e-9);
+    /// ```
+    pub fn circular_mean<'a>(bearings: impl IntoIterator<Item = &'a BearingValue>) -> Option<BearingValue> {
+        let mut count = 0usize;
+        let mut reference = None;
+        let (mut east, mut north) = (0.0, 0.0);
+        for bearing in bearings {
+            if *reference.get_or_insert(bearing.reference) != bearing.reference {
+                return None;
+            }
+            east += bearing.value.sin();
+            north += bearing.value.cos();
+            count += 1;
+        }
+        // the sums keep a few ulps of rounding even when the vectors cancel
+        if count == 0 || east.hypot(north) <= 1e-9 * count as f64 {
+            return None;
+        }
+        Some(BearingValue::from_si_value(east.atan2(north)).with_reference(reference?))
+    }
+}
+
+/// Formatting and parsing bearings as text
+impl BearingValue {
+    /// Get the name of the nearest point of the compass.
+    /// Bearings halfway between two points take the clockwise one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let bearing = BearingValue::new(33.75, &BearingUnit::Degrees);
+    /// assert_eq!(bearing.compass_point(CompassPoints::Sixteen), "NE");
+    /// assert_eq!(bearing.compass_point(CompassPoints::ThirtyTwo), "NE by N");
+    /// ```
+    pub fn compass_point(&self, points: CompassPoints) -> &'static str {
+        let count = points.count();
+        let index = (self.value / TAU * count as f64).round() as usize % count;
+        COMPASS_POINTS[index * (COMPASS_POINTS.len() / count)]
+    }
+
+    /// Parse the name of one of the 32 points of the compass.
+    /// Accepts abbreviations ("NNE", "NEbN", "NE by N") and words
+    /// ("North", "North-east by north"), in any case.
+    ///
+    /// Returns an error if the text is not the name of a compass point.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let bearing = BearingValue::parse_compass_point("SW by W").unwrap();
+    /// assert_eq!(bearing.value_in(&BearingUnit::Degrees), 236.25);
+    /// ```
+    pub fn parse_compass_point(input: &str) -> Result<BearingValue, UnitError> {
+        // only letters, so "45 N" is not read as north
+        let letters = input.chars().all(|c| c.is_alphabetic() || c == ' ' || c == '-');
+        let name = normalize_compass_point(input);
+        COMPASS_POINTS
+            .iter()
+            .position(|point| letters && normalize_compass_point(point) == name)
+            .map(|index| BearingValue::new(index as f64 * 11.25, &BearingUnit::Degrees))
+            .ok_or_else(|| UnitError::InvalidSyntax {
+                input: String::from(input),
+                reason: String::from("not a point of the compass"),
+            })
+    }
+
+    /// Format the bearing as a three digit heading, rounded to whole degrees.
+    /// North, including a bearing that rounds up to 360°, is written as "000°",
+    /// as in `format_dms` and the [0°, 360°) range of the bearing.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// assert_eq!(BearingValue::new(45.0, &BearingUnit::Degrees).format_heading(), "045°");
+    /// assert_eq!(BearingValue::new(359.7, &BearingUnit::Degrees).format_heading(), "000°");
+    /// ```
+    pub fn format_heading(&self) -> String {
+        let degrees = self.value.to_degrees().round() as u32 % 360;
+        format!("{degrees:03}°")
+    }
+
+    /// Parse a heading of whole degrees, such as "045°" or "270".
+    /// Both "000" and "360" are north.
+    ///
+    /// Returns an error if the text is not one to three digits, with an optional "°",
+    /// or if the heading is above 360.
+    pub fn parse_heading(input: &str) -> Result<BearingValue, UnitError> {
+        let error = |reason: &str| UnitError::InvalidSyntax {
+            input: String::from(input),
+            reason: String::from(reason),
+        };
+        let digits = input.trim();
+        let digits = digits.strip_suffix('°').unwrap_or(digits);
+        if digits.is_empty() || digits.len() > 3 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(error("expected a heading of up to three digits"));
+        }
+        let degrees: u32 = digits.parse().map_err(|_| error("expected a heading of up to three digits"))?;
+        if degrees > 360 {
+            return Err(error("a heading cannot be above 360°"));
+        }
+        Ok(BearingValue::new(f64::from(degrees), &BearingUnit::Degrees))
+    }
+
+    /// Format the bearing as degrees, minutes and seconds,
+    /// with `decimals` digits after the decimal point of the seconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::bearing::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let bearing = BearingValue::new(45.50430555555556, &BearingUnit::Degrees);
+    /// assert_eq!(bearing.format_dms(1), "45°30'15.5\"");
+    /// ```
+    pub fn format_dms(&self, decimals: usize) -> String {
+        // round once, in the smallest unit shown, so 59.96" carries into the minutes
+        // and a bearing that rounds up to 360° is written as north, 0°
+        let step = 10f64.powi(decimals as i32);
+        let total = (self.value.to_degrees() * 3600.0 * step).round() / step;
+        let total = if total >= 360.0 * 3600.0 { 0.0 } else { total };
+        let degrees = (total / 3600.0).floor();
+        let minutes = ((total - degrees * 3600.0) / 60.0).floor();
+        let seconds = total - degrees * 3600.0 - minutes * 60.0;
+        format!("{degrees}°{minutes}'{seconds:.decimals$}\"")
+    }
+
+    /// Parse a bearing written as degrees, minutes and seconds,
+    /// such as "45°30'15.5\"" or "45° 30′ 15.5″". The seconds, or both the
+    /// minutes and the seconds, may be left out.
+    ///
+    /// Returns an error if the text does not parse,
+    /// or if the minutes or seconds are not below 60.
+    pub fn parse_dms(input: &str) -> Result<BearingValue, UnitError> {
+        let error = |reason: &str| UnitError::InvalidSyntax {
+            input: String::from(input),
+            reason: String::from(reason),
+        };
+        let (sign, mut rest) = match input.trim().strip_prefix(['-', '−']) {
+            Some(rest) => (-1.0, rest),
+            None => (1.0, input.trim()),
+        };
+        let mut degrees = 0.0;
+        // each part must follow the one before: degrees, then minutes, then seconds
+        let mut next_part = 0;
+        while !rest.trim().is_empty() {
+            let (value, symbol, tail) = split_dms_part(rest).ok_or_else(|| error("expected a number and a symbol"))?;
+            let (part, size) = match symbol {
+                '°' => (0, 1.0),
+                '\'' | '′' => (1, 60.0),
+                '"' | '″' => (2, 3600.0),
+                _ => return Err(error("expected °, ' or \"")),
+            };
+            if part < next_part {
+                return Err(error("expected degrees, minutes and seconds in order"));
+            }
+            if part > 0 && value >= 60.0 {
+                return Err(error("minutes and seconds must be below 60"));
+            }
+            degrees += value / size;
+            next_part = part + 1;
+            rest = tail;
+        }
+        if next_part == 0 {
+            return Err(error("expected degrees"));
+        }
+        BearingValue::try_new(sign * degrees, &BearingUnit::Degrees)
+    }
+}
+
+/// Implementing FromStr trait for BearingValue
+/// Same as `BearingValue::parse`.
+impl std::str::FromStr for BearingValue {
+    type Err = UnitError;
+
+    fn from_str(input: &str) -> Result<BearingValue, UnitError> {
+        BearingValue::parse(input)
+    }
+}
+
+/// Implementing Value trait for BearingValue
+impl Value for BearingValue {
+    type Unit = BearingUnit;
+
+    /// Create a new true BearingValue from a value in Radians, normalised to [0, 2π).
+    fn from_si_value(value: f64) -> Self {
+        BearingValue {
+            value: wrap_positive(value),
+            reference: BearingReference::True,
+        }
+    }
+
+    /// Get the value in Radians, in [0, 2π).
+    fn si_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the unit the value is stored in.
+    fn unit_default() -> UnitEnum {
+        BearingUnit::default()
+    }
+
+    /// Get the bearing in the given unit, in [0°, 360°).
+    fn try_value_in(&self, unit: &BearingUnit) -> Result<f64, UnitError> {
+        unit.try_convert(self.value, &Self::unit_default(), &unit.unit_enum())
+    }
+
+    /// Set self to the given bearing in the given unit, normalised to [0°, 360°),
+    /// keeping the north it is measured from.
+    fn try_set(&mut self, value: f64, unit: &BearingUnit) -> Result<(), UnitError> {
+        *self = Self::try_new(value, unit)?.with_reference(self.reference);
+        Ok(())
+    }
+
+    /// Create a new true BearingValue from a bearing in the given unit, normalised to [0°, 360°).
+    ///
+    /// Returns an error if the value is not finite.
+    fn try_new(value: f64, unit: &BearingUnit) -> Result<Self, UnitError> {
+        let value = unit.try_convert(value, &unit.unit_enum(), &Self::unit_default())?;
+        Ok(BearingValue::from_si_value(value))
+    }
+}
+
+/// Implementing Display trait for BearingValue
+/// Writes the bearing in Radians; use `format_heading`, `format_dms` or
+/// `compass_point` for the usual forms.
+impl std::fmt::Display for BearingValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&ValueFormat::from_formatter(f).format_in(self.value, &Self::unit_default()))
+    }
+}
+
+/// The shortest turn from rhs to self
+/// Panics if the bearings are measured from different norths,
+/// use `BearingValue::try_turn_to` to handle that case.
+impl std::ops::Sub for BearingValue {
+    type Output = AngleValue;
+
+    fn sub(self, rhs: BearingValue) -> AngleValue {
+        rhs.turn_to(&self)
+    }
+}
+
+/// Turning clockwise by an angle, wrapping around north
+impl std::ops::Add<AngleValue> for BearingValue {
+    type Output = BearingValue;
+
+    fn add(self, rhs: AngleValue) -> BearingValue {
+        self.rotated(rhs.si_value())
+    }
+}
+
+/// Turning clockwise by an angle, wrapping around north
+impl std::ops::Add<BearingValue> for AngleValue {
+    type Output = BearingValue;
+
+    fn add(self, rhs: BearingValue) -> BearingValue {
+        rhs + self
+    }
+}
+
+/// Turning anticlockwise by an angle, wrapping around north
+impl std::ops::Sub<AngleValue> for BearingValue {
+    type Output = BearingValue;
+
+    fn sub(self, rhs: AngleValue) -> BearingValue {
+        self.rotated(-rhs.si_value())
+    }
+}
+
+/// Turning clockwise in place
+impl std::ops::AddAssign<AngleValue> for BearingValue {
+    fn add_assign(&mut self, rhs: AngleValue) {
+        *self = *self + rhs;
+    }
+}
+
+/// Turning anticlockwise in place
+impl std::ops::SubAssign<AngleValue> for BearingValue {
+    fn sub_assign(&mut self, rhs: AngleValue) {
+        *self = *self - rhs;
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get a bearing in Degrees
+    fn degrees(value: f64) -> BearingValue {
+        BearingValue::new(value, &BearingUnit::Degrees)
+    }
+
+    /// Check that two angles in Degrees are equal within rounding
+    fn assert_degrees(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "got {actual}°, expected {expected}°");
+    }
+
+    #[test]
+    fn test_normalisation() {
+        assert_degrees(degrees(370.0).value_in(&BearingUnit::Degrees), 10.0);
+        assert_degrees(degrees(-90.0).value_in(&BearingUnit::Degrees), 270.0);
+        assert_degrees(degrees(720.0).value_in(&BearingUnit::Degrees), 0.0);
+        assert_eq!(BearingValue::from_si_value(-1e-20).si_value(), 0.0);
+        assert_degrees(degrees(190.0).value_in_signed(&BearingUnit::Degrees), -170.0);
+        assert_degrees(degrees(180.0).value_in_signed(&BearingUnit::Degrees), 180.0);
+        assert!(BearingValue::try_new(f64::NAN, &BearingUnit::Degrees).is_err());
+        assert_eq!("-90 deg".parse::<BearingValue>(), Ok(degrees(270.0)));
+    }
+
+    #[test]
+    fn test_reciprocal_and_turns() {
+        assert_degrees(degrees(45.0).reciprocal().value_in(&BearingUnit::Degrees), 225.0);
+        assert_degrees(degrees(300.0).reciprocal().value_in(&BearingUnit::Degrees), 120.0);
+
+        let heading = degrees(350.0);
+        assert_degrees(heading.turn_to(&degrees(20.0)).value_in(&BearingUnit::Degrees), 30.0);
+        assert_degrees((degrees(20.0) - heading).value_in(&BearingUnit::Degrees), 30.0);
+        assert_degrees(degrees(20.0).turn_to(&heading).value_in(&BearingUnit::Degrees), -30.0);
+        assert_eq!(heading.turn_direction_to(&degrees(20.0)), TurnDirection::Right);
+        assert_eq!(heading.turn_direction_to(&degrees(300.0)), TurnDirection::Left);
+        assert_eq!(heading.turn_direction_to(&heading), TurnDirection::None);
+        assert_eq!(degrees(0.0).turn_direction_to(&degrees(180.0)), TurnDirection::Right);
+    }
+
+    #[test]
+    fn test_wrapping_arithmetic() {
+        let mut bearing = degrees(350.0) + AngleValue::new(20.0, &BearingUnit::Degrees);
+        assert_degrees(bearing.value_in(&BearingUnit::Degrees), 10.0);
+        bearing -= AngleValue::new(30.0, &BearingUnit::Degrees);
+        assert_degrees(bearing.value_in(&BearingUnit::Degrees), 340.0);
+        bearing += AngleValue::new(-720.0, &BearingUnit::Degrees);
+        assert_degrees(bearing.value_in(&BearingUnit::Degrees), 340.0);
+    }
+
+    #[test]
+    fn test_references() {
+        let offsets = NorthOffsets::new(
+            AngleValue::new(-12.0, &BearingUnit::Degrees),
+            AngleValue::new(2.0, &BearingUnit::Degrees),
+        );
+        let compass = degrees(5.0).with_reference(BearingReference::Magnetic);
+        let chart = compass.to_reference(BearingReference::True, &offsets);
+        assert_degrees(chart.value_in(&BearingUnit::Degrees), 353.0);
+        let map = compass.to_reference(BearingReference::Grid, &offsets);
+        assert_eq!(map.reference(), BearingReference::Grid);
+        assert_degrees(map.value_in(&BearingUnit::Degrees), 351.0);
+        assert_degrees(map.to_reference(BearingReference::Magnetic, &offsets).value_in(&BearingUnit::Degrees), 5.0);
+
+        // arithmetic keeps the reference, and turns need the same reference
+        assert_eq!((compass + AngleValue::new(10.0, &BearingUnit::Degrees)).reference(), BearingReference::Magnetic);
+        assert_eq!(compass.reciprocal().reference(), BearingReference::Magnetic);
+        assert_eq!(
+            compass.try_turn_to(&chart),
+            Err(UnitError::ReferenceMismatch { expected: BearingReference::Magnetic, found: BearingReference::True })
+        );
+        assert!(compass.try_turn_direction_to(&chart).is_err());
+        assert_eq!(compass.try_turn_direction_to(&compass), Ok(TurnDirection::None));
+        assert_eq!(BearingValue::circular_mean(&[compass, chart]), None);
+        let mean = BearingValue::circular_mean(&[compass, compass]).unwrap();
+        assert_eq!(mean.reference(), BearingReference::Magnetic);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a True bearing, found a Magnetic bearing")]
+    fn test_sub_panics_on_reference_mismatch() {
+        let _ = degrees(10.0).with_reference(BearingReference::Magnetic) - degrees(20.0);
+    }
+
+    #[test]
+    fn test_compass_points() {
+        assert_eq!(degrees(0.0).compass_point(CompassPoints::Four), "N");
+        assert_eq!(degrees(44.0).compass_point(CompassPoints::Four), "N");
+        assert_eq!(degrees(46.0).compass_point(CompassPoints::Four), "E");
+        assert_eq!(degrees(200.0).compass_point(CompassPoints::Eight), "S");
+        assert_eq!(degrees(22.5).compass_point(CompassPoints::Sixteen), "NNE");
+        assert_eq!(degrees(355.0).compass_point(CompassPoints::Sixteen), "N");
+        assert_eq!(degrees(348.75).compass_point(CompassPoints::ThirtyTwo), "N by W");
+
+        for index in 0..32 {
+            let bearing = degrees(index as f64 * 11.25);
+            let name = bearing.compass_point(CompassPoints::ThirtyTwo);
+            assert_eq!(BearingValue::parse_compass_point(name), Ok(bearing), "{name}");
+        }
+        assert_eq!(BearingValue::parse_compass_point("NEbN"), Ok(degrees(33.75)));
+        assert_eq!(BearingValue::parse_compass_point("north-east by east"), Ok(degrees(56.25)));
+        assert_eq!("wsw".parse::<BearingValue>(), Ok(degrees(247.5)));
+        assert!(BearingValue::parse_compass_point("NNNE").is_err());
+        assert!(BearingValue::parse_compass_point("45 N").is_err());
+    }
+
+    #[test]
+    fn test_headings() {
+        assert_eq!(degrees(5.4).format_heading(), "005°");
+        assert_eq!(degrees(270.0).format_heading(), "270°");
+        assert_eq!(degrees(0.2).format_heading(), "000°");
+        assert_eq!(BearingValue::parse_heading("045°"), Ok(degrees(45.0)));
+        assert_eq!(BearingValue::parse_heading("360"), Ok(degrees(0.0)));
+        assert_eq!("090".parse::<BearingValue>(), Ok(degrees(90.0)));
+        assert!(BearingValue::parse_heading("361").is_err());
+        assert!(BearingValue::parse_heading("0450").is_err());
+        assert!(BearingValue::parse_heading("45.5").is_err());
+    }
+
+    #[test]
+    fn test_dms() {
+        let bearing = BearingValue::parse_dms("45°30'15\"").unwrap();
+        assert_degrees(bearing.value_in(&BearingUnit::Degrees), 45.50416666666667);
+        assert_eq!(bearing.format_dms(0), "45°30'15\"");
+        assert_eq!(bearing.format_dms(2), "45°30'15.00\"");
+        assert_eq!(degrees(10.999999).format_dms(1), "11°0'0.0\"");
+        assert_eq!(degrees(359.9999999).format_dms(0), "0°0'0\"");
+
+        // a bearing that rounds up to north is written as 0° in both forms
+        let almost_north = degrees(359.99999);
+        assert_eq!(almost_north.format_heading(), "000°");
+        assert_eq!(almost_north.format_dms(0), "0°0'0\"");
+        assert_eq!(degrees(359.7).format_heading(), "000°");
+        assert_eq!(degrees(359.4).format_heading(), "359°");
+
+        assert_eq!(BearingValue::parse_dms("45° 30′ 15″"), Ok(bearing));
+        assert_eq!("45°30'15\"".parse::<BearingValue>(), Ok(bearing));
+        assert_degrees(BearingValue::parse_dms("12°30'").unwrap().value_in(&BearingUnit::Degrees), 12.5);
+        assert_degrees(BearingValue::parse_dms("-10°").unwrap().value_in(&BearingUnit::Degrees), 350.0);
+        assert!(BearingValue::parse_dms("45°75'").is_err());
+        assert!(BearingValue::parse_dms("30'45°").is_err());
+        assert!(BearingValue::parse_dms("45").is_err());
+        assert!(BearingValue::parse_dms("").is_err());
+    }
+
+    #[test]
+    fn test_circular_mean() {
+        let mean = BearingValue::circular_mean(&[degrees(350.0), degrees(10.0)]).unwrap();
+        assert_degrees(mean.value_in_signed(&BearingUnit::Degrees), 0.0);
+        let mean = BearingValue::circular_mean(&[degrees(80.0), degrees(90.0), degrees(100.0)]).unwrap();
+        assert_degrees(mean.value_in(&BearingUnit::Degrees), 90.0);
+        assert_eq!(BearingValue::circular_mean(&[]), None);
+        assert_eq!(BearingValue::circular_mean(&[degrees(0.0), degrees(180.0)]), None);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::error::UnitError;
+
+/// Dimension struct
+///
+/// The physical dimension of a value, as the exponents of the seven SI base
+/// dimensions plus plane angle.
+///
+/// Example: Force is `Length¹ Mass¹ Time⁻²`, i.e. `Dimension::FORCE`.
+///
+/// The field order matches the const parameters of `Quantity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Dimension {
+    /// exponent of Length (m)
+    pub length: i8,
+    /// exponent of Mass (kg)
+    pub mass: i8,
+    /// exponent of Time (s)
+    pub time: i8,
+    /// exponent of Temperature (K)
+    pub temperature: i8,
+    /// exponent of Electric Current (A)
+    pub current: i8,
+    /// exponent of Amount of Substance (mol)
+    pub amount: i8,
+    /// exponent of Luminous Intensity (cd)
+    pub luminous_intensity: i8,
+    /// exponent of Angle (rad)
+    pub angle: i8,
+}
+
+/// SI symbols of the base dimensions, in field order.
+const BASE_SYMBOLS: [&str; 8] = ["m", "kg", "s", "K", "A", "mol", "cd", "rad"];
+
+impl Dimension {
+    /// A dimensionless number
+    pub const DIMENSIONLESS: Dimension = Dimension::from_exponents([0, 0, 0, 0, 0, 0, 0, 0]);
+    /// Length, measured in Meters
+    pub const LENGTH: Dimension = Dimension::from_exponents([1, 0, 0, 0, 0, 0, 0, 0]);
+    /// Mass, measured in Kilograms
+    pub const MASS: Dimension = Dimension::from_exponents([0, 1, 0, 0, 0, 0, 0, 0]);
+    /// Time, measured in Seconds
+    pub const TIME: Dimension = Dimension::from_exponents([0, 0, 1, 0, 0, 0, 0, 0]);
+    /// Temperature, measured in Kelvin
+    pub const TEMPERATURE: Dimension = Dimension::from_exponents([0, 0, 0, 1, 0, 0, 0, 0]);
+    /// Velocity, measured in Meters Per Second
+    pub const VELOCITY: Dimension = Dimension::from_exponents([1, 0, -1, 0, 0, 0, 0, 0]);
+    /// Force, measured in Newtons
+    pub const FORCE: Dimension = Dimension::from_exponents([1, 1, -2, 0, 0, 0, 0, 0]);
+    /// Pressure, measured in Pascals
+    pub const PRESSURE: Dimension = Dimension::from_exponents([-1, 1, -2, 0, 0, 0, 0, 0]);
+    /// Angle (used for bearings), measured in Radians
+    pub const ANGLE: Dimension = Dimension::from_exponents([0, 0, 0, 0, 0, 0, 0, 1]);
+    /// Acceleration, measured in Meters Per Second Squared
+    pub const ACCELERATION: Dimension = Dimension::from_exponents([1, 0, -2, 0, 0, 0, 0, 0]);
+    /// Area, measured in Square Meters
+    pub const AREA: Dimension = Dimension::from_exponents([2, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Create a Dimension from its exponents, in field order.
+    pub const fn from_exponents(exponents: [i8; 8]) -> Dimension {
+        Dimension {
+            length: exponents[0],
+            mass: exponents[1],
+            time: exponents[2],
+            temperature: exponents[3],
+            current: exponents[4],
+            amount: exponents[5],
+            luminous_intensity: exponents[6],
+            angle: exponents[7],
+        }
+    }
+
+    /// Get the exponents of the Dimension, in field order.
+    pub const fn exponents(&self) -> [i8; 8] {
+        [
+            self.length,
+            self.mass,
+            self.time,
+            self.temperature,
+            self.current,
+            self.amount,
+            self.luminous_intensity,
+            self.angle,
+        ]
+    }
+
+    /// Multiply by another Dimension, which adds the exponents.
+    ///
+    /// Returns an error if an exponent does not fit in an i8.
+    pub fn try_mul(self, rhs: Dimension) -> Result<Dimension, UnitError> {
+        let (lhs, rhs) = (self.exponents(), rhs.exponents());
+        checked_exponents(|i| {
+            lhs[i]
+                .checked_add(rhs[i])
+                .ok_or(i32::from(lhs[i]) + i32::from(rhs[i]))
+        })
+    }
+
+    /// Divide by another Dimension, which subtracts the exponents.
+    ///
+    /// Returns an error if an exponent does not fit in an i8.
+    pub fn try_div(self, rhs: Dimension) -> Result<Dimension, UnitError> {
+        let (lhs, rhs) = (self.exponents(), rhs.exponents());
+        checked_exponents(|i| {
+            lhs[i]
+                .checked_sub(rhs[i])
+                .ok_or(i32::from(lhs[i]) - i32::from(rhs[i]))
+        })
+    }
+
+    /// Raise the Dimension to an integer power.
+    /// Example: `Dimension::LENGTH.try_powi(2)` is `Ok(Dimension::AREA)`
+    ///
+    /// Returns an error if an exponent does not fit in an i8.
+    pub fn try_powi(self, power: i8) -> Result<Dimension, UnitError> {
+        let exponents = self.exponents();
+        checked_exponents(|i| {
+            exponents[i]
+                .checked_mul(power)
+                .ok_or(i32::from(exponents[i]) * i32::from(power))
+        })
+    }
+
+    /// Raise the Dimension to an integer power.
+    /// Panicking wrapper around `try_powi`.
+    pub fn powi(self, power: i8) -> Dimension {
+        self.try_powi(power)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Get the name of a well-known Dimension.
+    /// Example: "Velocity"
+    pub fn name(&self) -> Option<&'static str> {
+        match *self {
+            Dimension::DIMENSIONLESS => Some("Dimensionless"),
+            Dimension::LENGTH => Some("Length"),
+            Dimension::MASS => Some("Mass"),
+            Dimension::TIME => Some("Time"),
+            Dimension::TEMPERATURE => Some("Temperature"),
+            Dimension::VELOCITY => Some("Velocity"),
+            Dimension::FORCE => Some("Force"),
+            Dimension::PRESSURE => Some("Pressure"),
+            Dimension::ANGLE => Some("Angle"),
+            Dimension::ACCELERATION => Some("Acceleration"),
+            Dimension::AREA => Some("Area"),
+            _ => None,
+        }
+    }
+
+    /// Get the Dimension as a product of SI base units.
+    /// Example: "m·kg·s⁻²" for Force
+    pub fn si_symbol(&self) -> String {
+        let parts: Vec<String> = self
+            .exponents()
+            .iter()
+            .zip(BASE_SYMBOLS)
+            .filter(|(exponent, _)| **exponent != 0)
+            .map(|(exponent, symbol)| match exponent {
+                1 => String::from(symbol),
+                _ => format!("{symbol}{}", superscript(*exponent)),
+            })
+            .collect();
+        if parts.is_empty() {
+            String::from("1")
+        } else {
+            parts.join("·")
+        }
+    }
+}
+
+/// Build a Dimension from the checked exponent of each base dimension,
+/// in field order, where an error holds the exponent that did not fit in an i8.
+/// The OutOfDomain error names the exponent and the SI symbol of its base dimension.
+fn checked_exponents(exponent: impl Fn(usize) -> Result<i8, i32>) -> Result<Dimension, UnitError> {
+    let mut exponents = [0; 8];
+    for (i, slot) in exponents.iter_mut().enumerate() {
+        *slot = exponent(i).map_err(|value| UnitError::OutOfDomain {
+            value: value.into(),
+            unit: format!("{} exponent", BASE_SYMBOLS[i]),
+        })?;
+    }
+    Ok(Dimension::from_exponents(exponents))
+}
+
+/// Write an exponent with Unicode superscript digits.
+/// Example: -2 -> "⁻²"
+fn superscript(exponent: i8) -> String {
+    exponent
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
+/// Multiplying values multiplies their dimensions, which adds the exponents.
+/// Panics if an exponent overflows, see `Dimension::try_mul`.
+impl std::ops::Mul for Dimension {
+    type Output = Dimension;
+
+    fn mul(self, rhs: Dimension) -> Dimension {
+        self.try_mul(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Dividing values divides their dimensions, which subtracts the exponents.
+/// Panics if an exponent overflows, see `Dimension::try_div`.
+impl std::ops::Div for Dimension {
+    type Output = Dimension;
+
+    fn div(self, rhs: Dimension) -> Dimension {
+        self.try_div(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Implementing Display trait for Dimension
+/// Displays the name of well-known dimensions, and the SI base units otherwise.
+/// Example: "Force" | "m·kg"
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{}", self.si_symbol()),
+        }
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimension_arithmetic() {
+        assert_eq!(Dimension::LENGTH / Dimension::TIME, Dimension::VELOCITY);
+        assert_eq!(Dimension::MASS * Dimension::ACCELERATION, Dimension::FORCE);
+        assert_eq!(Dimension::FORCE / Dimension::AREA, Dimension::PRESSURE);
+        assert_eq!(Dimension::LENGTH.powi(2), Dimension::AREA);
+        assert_eq!(Dimension::LENGTH / Dimension::LENGTH, Dimension::DIMENSIONLESS);
+    }
+
+    #[test]
+    fn test_dimension_overflow() {
+        let big = Dimension::LENGTH.powi(100);
+        assert_eq!(
+            big.try_mul(big),
+            Err(UnitError::OutOfDomain { value: 200.0, unit: String::from("m exponent") })
+        );
+        assert_eq!(
+            Dimension::TIME.powi(-100).try_div(Dimension::TIME.powi(100)),
+            Err(UnitError::OutOfDomain { value: -200.0, unit: String::from("s exponent") })
+        );
+        assert_eq!(
+            big.try_powi(2),
+            Err(UnitError::OutOfDomain { value: 200.0, unit: String::from("m exponent") })
+        );
+        assert_eq!(Dimension::LENGTH.try_powi(-128).map(|dim| dim.length), Ok(-128));
+        assert_eq!(big.try_div(big), Ok(Dimension::DIMENSIONLESS));
+    }
+
+    #[test]
+    fn test_dimension_display() {
+        assert_eq!(Dimension::FORCE.to_string(), "Force");
+        assert_eq!(Dimension::FORCE.si_symbol(), "m·kg·s⁻²");
+        assert_eq!((Dimension::MASS * Dimension::LENGTH).to_string(), "m·kg");
+        assert_eq!(Dimension::DIMENSIONLESS.si_symbol(), "1");
+    }
+}
@@ -0,0 +1,442 @@
+use crate::bearing::BearingValue;
+use crate::dimension::Dimension;
+use crate::error::UnitError;
+use crate::units::*;
+use crate::values::{Absolute, Quantity, Value};
+
+/// DynQuantity struct
+///
+/// A value whose dimension is only known at runtime, such as one read from a
+/// config file or computed from a user-entered formula.
+/// The value is stored in the SI unit of its dimension.
+///
+/// Multiplying and dividing DynQuantities combines the dimensions, checking
+/// that no exponent overflows. Adding and subtracting checks that the dimensions match.
+///
+/// Each DynQuantity also records its `QuantityKind`, whether it is an absolute
+/// value such as a temperature of 20 °C, or a difference such as a change of 10 °C.
+/// Differences convert without the offset of the unit, and adding two absolute
+/// values is an error, as with the typed `Absolute` values.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::dynamic::*;
+/// use units_and_values::units::*;
+///
+/// let distance = DynQuantity::from_unit(3.0, &UnitEnum::Length(LengthUnit::Kilometers));
+/// let time = DynQuantity::from_unit(10.0, &UnitEnum::Time(TimeUnit::Minutes));
+/// let speed = distance / time;
+/// assert_eq!(speed.value_in(&UnitEnum::Velocity(VelocityUnit::KilometersPerHour)), 18.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynQuantity {
+    /// value in the SI unit of the dimension
+    si_value: f64,
+    /// dimension of the value
+    dim: Dimension,
+    /// whether the value is absolute or a difference
+    kind: QuantityKind,
+}
+
+/// Whether a DynQuantity is measured from the zero of its scale or is a difference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantityKind {
+    /// a point on a scale, such as an absolute temperature, from a typed `Absolute` value or a bearing
+    Absolute,
+    /// a difference between two points, from a typed `Quantity`
+    Delta,
+    /// either, for a value in a unit that shares the SI zero,
+    /// where the two kinds convert the same way
+    #[default]
+    Unspecified,
+}
+
+/// Implementing Display trait for QuantityKind
+impl std::fmt::Display for QuantityKind {
+    /// Display the kind name
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Absolute => write!(f, "Absolute"),
+            Self::Delta => write!(f, "Delta"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        }
+    }
+}
+
+impl DynQuantity {
+    /// Create a new DynQuantity of unspecified kind
+    /// from a value in the SI unit of the given dimension.
+    pub fn new(si_value: f64, dim: Dimension) -> DynQuantity {
+        DynQuantity { si_value, dim, kind: QuantityKind::Unspecified }
+    }
+
+    /// Create a new DynQuantity from a value in the given unit.
+    /// A value in a unit with an offset from the SI zero, such as Celcius or psig,
+    /// is absolute, and a value in any other unit is of unspecified kind.
+    ///
+    /// Returns an error if the value is not finite or out of the unit's domain.
+    pub fn try_from_unit(value: f64, unit: &UnitEnum) -> Result<DynQuantity, UnitError> {
+        let kind = if unit.si_conversion().offset != 0.0 {
+            QuantityKind::Absolute
+        } else {
+            QuantityKind::Unspecified
+        };
+        Ok(DynQuantity::new(unit.try_to_si(value)?, unit.dimension()).with_kind(kind))
+    }
+
+    /// Create a new DynQuantity from a value in the given unit.
+    /// Panicking wrapper around `try_from_unit`.
+    pub fn from_unit(value: f64, unit: &UnitEnum) -> DynQuantity {
+        Self::try_from_unit(value, unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Get the value in the SI unit of the dimension.
+    pub fn si_value(&self) -> f64 {
+        self.si_value
+    }
+
+    /// Get the dimension of the value.
+    pub fn dimension(&self) -> Dimension {
+        self.dim
+    }
+
+    /// Get whether the value is absolute or a difference.
+    pub fn kind(&self) -> QuantityKind {
+        self.kind
+    }
+
+    /// Get the same value with a different kind.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::dimension::Dimension;
+    /// use units_and_values::dynamic::*;
+    /// use units_and_values::units::*;
+    ///
+    /// let change = DynQuantity::new(10.0, Dimension::TEMPERATURE).with_kind(QuantityKind::Delta);
+    /// assert_eq!(change.value_in(&UnitEnum::Temperature(TemperatureUnit::Celcius)), 10.0);
+    /// ```
+    pub fn with_kind(&self, kind: QuantityKind) -> DynQuantity {
+        DynQuantity { kind, ..*self }
+    }
+
+    /// Get the value in the given unit.
+    /// A difference converts without the offset of the unit, so a change of 10 K is a change of 10 °C.
+    ///
+    /// Returns an error if the unit measures a different dimension.
+    pub fn try_value_in(&self, unit: &UnitEnum) -> Result<f64, UnitError> {
+        self.check_dimension(unit.dimension())?;
+        match self.kind {
+            QuantityKind::Delta => unit.try_delta_from_si(self.si_value),
+            _ => unit.try_from_si(self.si_value),
+        }
+    }
+
+    /// Get the value in the given unit.
+    /// Panicking wrapper around `try_value_in`.
+    pub fn value_in(&self, unit: &UnitEnum) -> f64 {
+        self.try_value_in(unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Add a DynQuantity of the same dimension.
+    /// Adding a difference to an absolute value gives an absolute value.
+    ///
+    /// Returns an error if the dimensions differ, or if both values are absolute.
+    pub fn try_add(&self, other: &DynQuantity) -> Result<DynQuantity, UnitError> {
+        self.check_dimension(other.dim)?;
+        let kind = match (self.kind, other.kind) {
+            (QuantityKind::Absolute, QuantityKind::Absolute) => {
+                return Err(UnitError::KindMismatch { expected: QuantityKind::Delta, found: QuantityKind::Absolute });
+            }
+            (QuantityKind::Absolute, _) | (_, QuantityKind::Absolute) => QuantityKind::Absolute,
+            (QuantityKind::Delta, _) | (_, QuantityKind::Delta) => QuantityKind::Delta,
+            _ => QuantityKind::Unspecified,
+        };
+        Ok(DynQuantity::new(self.si_value + other.si_value, self.dim).with_kind(kind))
+    }
+
+    /// Subtract a DynQuantity of the same dimension.
+    /// The difference between two absolute values is a difference,
+    /// and subtracting a difference from an absolute value gives an absolute value.
+    ///
+    /// Returns an error if the dimensions differ,
+    /// or if an absolute value is subtracted from a difference.
+    pub fn try_sub(&self, other: &DynQuantity) -> Result<DynQuantity, UnitError> {
+        self.check_dimension(other.dim)?;
+        let kind = match (self.kind, other.kind) {
+            (QuantityKind::Delta, QuantityKind::Absolute) => {
+                return Err(UnitError::KindMismatch { expected: QuantityKind::Absolute, found: QuantityKind::Delta });
+            }
+            (_, QuantityKind::Absolute) => QuantityKind::Delta,
+            (QuantityKind::Absolute, _) => QuantityKind::Absolute,
+            (QuantityKind::Delta, _) | (_, QuantityKind::Delta) => QuantityKind::Delta,
+            _ => QuantityKind::Unspecified,
+        };
+        Ok(DynQuantity::new(self.si_value - other.si_value, self.dim).with_kind(kind))
+    }
+
+    /// Multiply by a DynQuantity, combining the dimensions.
+    /// The product is of unspecified kind.
+    ///
+    /// Returns an error if an exponent of the dimension overflows.
+    pub fn try_mul(&self, other: &DynQuantity) -> Result<DynQuantity, UnitError> {
+        Ok(DynQuantity::new(self.si_value * other.si_value, self.dim.try_mul(other.dim)?))
+    }
+
+    /// Divide by a DynQuantity, combining the dimensions.
+    /// The quotient is of unspecified kind.
+    ///
+    /// Returns an error if an exponent of the dimension overflows.
+    pub fn try_div(&self, other: &DynQuantity) -> Result<DynQuantity, UnitError> {
+        Ok(DynQuantity::new(self.si_value / other.si_value, self.dim.try_div(other.dim)?))
+    }
+
+    /// Raise the value and its dimension to an integer power.
+    /// Example: (3 m).try_powi(2) = 9 m²
+    ///
+    /// Returns an error if an exponent of the dimension overflows.
+    pub fn try_powi(&self, power: i8) -> Result<DynQuantity, UnitError> {
+        Ok(DynQuantity::new(self.si_value.powi(power.into()), self.dim.try_powi(power)?))
+    }
+
+    /// Raise the value and its dimension to an integer power.
+    /// Panicking wrapper around `try_powi`.
+    pub fn powi(&self, power: i8) -> DynQuantity {
+        self.try_powi(power)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Return an error unless `dim` is the dimension of self.
+    fn check_dimension(&self, dim: Dimension) -> Result<(), UnitError> {
+        if self.dim == dim {
+            Ok(())
+        } else {
+            Err(UnitError::DimensionMismatch { expected: self.dim, found: dim })
+        }
+    }
+
+    /// Return an error unless the value has the `expected` dimension
+    /// and can be read as the `expected` kind.
+    fn check_typed(&self, expected: Dimension, kind: QuantityKind) -> Result<(), UnitError> {
+        if self.dim != expected {
+            return Err(UnitError::DimensionMismatch { expected, found: self.dim });
+        }
+        if self.kind != kind && self.kind != QuantityKind::Unspecified {
+            return Err(UnitError::KindMismatch { expected: kind, found: self.kind });
+        }
+        Ok(())
+    }
+}
+
+/// Multiplying two DynQuantities, combining their dimensions
+/// Panics if an exponent overflows, see `DynQuantity::try_mul`.
+impl std::ops::Mul for DynQuantity {
+    type Output = DynQuantity;
+
+    fn mul(self, rhs: DynQuantity) -> DynQuantity {
+        self.try_mul(&rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Dividing two DynQuantities, combining their dimensions
+/// Panics if an exponent overflows, see `DynQuantity::try_div`.
+impl std::ops::Div for DynQuantity {
+    type Output = DynQuantity;
+
+    fn div(self, rhs: DynQuantity) -> DynQuantity {
+        self.try_div(&rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Scaling a DynQuantity by a number
+impl std::ops::Mul<f64> for DynQuantity {
+    type Output = DynQuantity;
+
+    fn mul(self, rhs: f64) -> DynQuantity {
+        DynQuantity { si_value: self.si_value * rhs, ..self }
+    }
+}
+
+/// Dividing a DynQuantity by a number
+impl std::ops::Div<f64> for DynQuantity {
+    type Output = DynQuantity;
+
+    fn div(self, rhs: f64) -> DynQuantity {
+        DynQuantity { si_value: self.si_value / rhs, ..self }
+    }
+}
+
+/// Negating a DynQuantity
+impl std::ops::Neg for DynQuantity {
+    type Output = DynQuantity;
+
+    fn neg(self) -> DynQuantity {
+        DynQuantity { si_value: -self.si_value, ..self }
+    }
+}
+
+/// Converting a Quantity into a DynQuantity, as a difference
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8>
+    From<Quantity<L, M, T, TH, I, N, J, A>> for DynQuantity
+{
+    fn from(quantity: Quantity<L, M, T, TH, I, N, J, A>) -> DynQuantity {
+        DynQuantity::new(quantity.si_value(), Quantity::<L, M, T, TH, I, N, J, A>::DIMENSION)
+            .with_kind(QuantityKind::Delta)
+    }
+}
+
+/// Converting a DynQuantity back into a Quantity
+/// Fails if the DynQuantity has a different dimension or is absolute.
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8>
+    TryFrom<DynQuantity> for Quantity<L, M, T, TH, I, N, J, A>
+{
+    type Error = UnitError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, UnitError> {
+        quantity.check_typed(Quantity::<L, M, T, TH, I, N, J, A>::DIMENSION, QuantityKind::Delta)?;
+        Ok(Quantity::from_si_value(quantity.si_value))
+    }
+}
+
+/// Converting an Absolute value into an absolute DynQuantity, measured from the SI zero
+impl<Q: Value> From<Absolute<Q>> for DynQuantity {
+    fn from(absolute: Absolute<Q>) -> DynQuantity {
+        DynQuantity::new(absolute.si_value(), Absolute::<Q>::unit_default().dimension())
+            .with_kind(QuantityKind::Absolute)
+    }
+}
+
+/// Converting a DynQuantity measured from the SI zero back into an Absolute value
+/// Fails if the DynQuantity has a different dimension or is a difference.
+impl<Q: Value> TryFrom<DynQuantity> for Absolute<Q> {
+    type Error = UnitError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Absolute<Q>, UnitError> {
+        quantity.check_typed(Absolute::<Q>::unit_default().dimension(), QuantityKind::Absolute)?;
+        Ok(Absolute::from_si_value(quantity.si_value))
+    }
+}
+
+/// Converting a bearing into an absolute DynQuantity in Radians
+impl From<BearingValue> for DynQuantity {
+    fn from(bearing: BearingValue) -> DynQuantity {
+        DynQuantity::new(bearing.si_value(), Dimension::ANGLE).with_kind(QuantityKind::Absolute)
+    }
+}
+
+/// Converting a DynQuantity in Radians into a bearing, normalised to [0, 2π)
+/// Fails if the DynQuantity is not an angle or is a difference, such as an AngleValue.
+impl TryFrom<DynQuantity> for BearingValue {
+    type Error = UnitError;
+
+    fn try_from(quantity: DynQuantity) -> Result<BearingValue, UnitError> {
+        quantity.check_typed(Dimension::ANGLE, QuantityKind::Absolute)?;
+        Ok(BearingValue::from_si_value(quantity.si_value))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::*;
+
+    #[test]
+    fn test_dyn_quantity_units() {
+        let pressure = DynQuantity::from_unit(1.0, &UnitEnum::Pressure(PressureUnit::Atmospheres));
+        assert_eq!(pressure.dimension(), Dimension::PRESSURE);
+        assert_eq!(pressure.si_value(), 101325.0);
+        assert_eq!(pressure.value_in(&UnitEnum::Pressure(PressureUnit::Hectopascals)), 1013.25);
+        assert_eq!(
+            pressure.try_value_in(&UnitEnum::Length(LengthUnit::Meters)),
+            Err(UnitError::DimensionMismatch { expected: Dimension::PRESSURE, found: Dimension::LENGTH })
+        );
+    }
+
+    #[test]
+    fn test_dyn_quantity_arithmetic() {
+        let mass = DynQuantity::from_unit(2.0, &UnitEnum::Mass(MassUnit::Kilograms));
+        let acceleration = DynQuantity::from_unit(1.0, &UnitEnum::Acceleration(AccelerationUnit::StandardGravity));
+        let force = mass * acceleration;
+        assert_eq!(force.dimension(), Dimension::FORCE);
+        assert_eq!(force.value_in(&UnitEnum::Force(ForceUnit::KilogramsForce)), 2.0);
+
+        assert!(force.try_add(&mass).is_err());
+        assert_eq!(force.try_sub(&force).unwrap().si_value(), 0.0);
+
+        let side = DynQuantity::from_unit(3.0, &UnitEnum::Length(LengthUnit::Meters));
+        assert_eq!(side.powi(2), DynQuantity::new(9.0, Dimension::AREA));
+        assert_eq!((side / side).dimension(), Dimension::DIMENSIONLESS);
+
+        let huge = side.powi(100);
+        assert!(matches!(huge.try_mul(&huge), Err(UnitError::OutOfDomain { .. })));
+        assert!(matches!(huge.try_powi(-2), Err(UnitError::OutOfDomain { .. })));
+        assert_eq!(huge.try_div(&huge).map(|ratio| ratio.dimension()), Ok(Dimension::DIMENSIONLESS));
+    }
+
+    #[test]
+    fn test_dyn_quantity_typed_round_trip() {
+        let speed = VelocityValue::new(10.0, &VelocityUnit::MetersPerSecond);
+        let dynamic = DynQuantity::from(speed);
+        assert_eq!(dynamic.dimension(), Dimension::VELOCITY);
+        assert_eq!(VelocityValue::try_from(dynamic), Ok(speed));
+        assert!(LengthValue::try_from(dynamic).is_err());
+
+        let temperature = TemperatureValue::new(20.0, &TemperatureUnit::Celcius);
+        assert_eq!(DynQuantity::from(temperature).si_value(), 293.15);
+        assert_eq!(TemperatureValue::try_from(DynQuantity::from(temperature)), Ok(temperature));
+        assert!(TemperatureValue::try_from(dynamic).is_err());
+    }
+
+    #[test]
+    fn test_dyn_quantity_kinds() {
+        let celcius = UnitEnum::Temperature(TemperatureUnit::Celcius);
+        let delta = DynQuantity::from(TemperatureDelta::new(10.0, &TemperatureUnit::Celcius));
+        assert_eq!(delta.kind(), QuantityKind::Delta);
+        assert_eq!(delta.value_in(&celcius), 10.0);
+        assert_eq!(delta.value_in(&UnitEnum::Temperature(TemperatureUnit::Fehrenheit)), 18.0);
+
+        let temperature = DynQuantity::from(TemperatureValue::new(20.0, &TemperatureUnit::Celcius));
+        assert_eq!(temperature.kind(), QuantityKind::Absolute);
+        assert_eq!(DynQuantity::from_unit(20.0, &celcius), temperature);
+        assert_eq!(
+            TemperatureValue::try_from(delta),
+            Err(UnitError::KindMismatch { expected: QuantityKind::Absolute, found: QuantityKind::Delta })
+        );
+        assert_eq!(
+            TemperatureDelta::try_from(temperature),
+            Err(UnitError::KindMismatch { expected: QuantityKind::Delta, found: QuantityKind::Absolute })
+        );
+
+        // an absolute value plus a difference is absolute, and two absolute values do not add
+        let warmer = temperature.try_add(&delta).unwrap();
+        assert_eq!(warmer.kind(), QuantityKind::Absolute);
+        assert!((warmer.value_in(&celcius) - 30.0).abs() < 1e-9);
+        assert_eq!(delta.try_add(&temperature).unwrap().kind(), QuantityKind::Absolute);
+        assert_eq!(
+            temperature.try_add(&temperature),
+            Err(UnitError::KindMismatch { expected: QuantityKind::Delta, found: QuantityKind::Absolute })
+        );
+        let change = warmer.try_sub(&temperature).unwrap();
+        assert_eq!(change.kind(), QuantityKind::Delta);
+        assert!((change.value_in(&celcius) - 10.0).abs() < 1e-9);
+        assert!(delta.try_sub(&temperature).is_err());
+
+        // values in units that share the SI zero read as either kind
+        let kelvin = DynQuantity::from_unit(300.0, &UnitEnum::Temperature(TemperatureUnit::Kelvin));
+        assert_eq!(kelvin.kind(), QuantityKind::Unspecified);
+        assert_eq!(TemperatureValue::try_from(kelvin).map(|value| value.si_value()), Ok(300.0));
+        assert_eq!(TemperatureDelta::try_from(kelvin).map(|value| value.si_value()), Ok(300.0));
+        let length = DynQuantity::from_unit(3.0, &UnitEnum::Length(LengthUnit::Kilometers));
+        assert_eq!(length.try_add(&length).unwrap().kind(), QuantityKind::Unspecified);
+
+        // bearings are absolute and AngleValues are differences
+        let bearing = BearingValue::new(90.0, &BearingUnit::Degrees);
+        assert_eq!(BearingValue::try_from(DynQuantity::from(bearing)), Ok(bearing));
+        assert!(BearingValue::try_from(DynQuantity::from(AngleValue::new(90.0, &BearingUnit::Degrees))).is_err());
+    }
+}
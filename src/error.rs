@@ -0,0 +1,95 @@
+use crate::bearing::BearingReference;
+use crate::dimension::Dimension;
+use crate::dynamic::QuantityKind;
+use crate::units::UnitEnum;
+
+/// Errors returned by the fallible conversion and parsing functions.
+///
+/// Every panicking function in the crate has a `try_` counterpart
+/// that returns one of these instead.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnitError {
+    /// The input string does not name a known unit.
+    /// Example: "furlongs"
+    UnknownUnit(String),
+
+    /// The input string names units of more than one dimension.
+    /// Example: "g", which is both Grams and Standard Gravity
+    AmbiguousUnit {
+        input: String,
+        candidates: Vec<UnitEnum>,
+    },
+
+    /// A unit of one dimension was given where another dimension was expected.
+    /// Example: Kilograms passed to a Length conversion.
+    DimensionMismatch {
+        expected: Dimension,
+        found: Dimension,
+    },
+
+    /// The input string could not be parsed.
+    /// Example: "kg*/s" in a unit expression
+    InvalidSyntax {
+        input: String,
+        reason: String,
+    },
+
+    /// Two bearings measured from different norths were combined.
+    /// Example: the turn from a magnetic bearing to a true bearing
+    ReferenceMismatch {
+        expected: BearingReference,
+        found: BearingReference,
+    },
+
+    /// An absolute value was given where a difference was expected, or the reverse.
+    /// Example: adding two absolute temperatures
+    KindMismatch {
+        expected: QuantityKind,
+        found: QuantityKind,
+    },
+
+    /// The value is NaN or infinite.
+    NonFinite(f64),
+
+    /// The value is outside the physical domain of the unit.
+    /// Example: -500 °F, which is below absolute zero.
+    OutOfDomain {
+        value: f64,
+        unit: String,
+    },
+}
+
+/// Implementing Display trait for UnitError
+impl std::fmt::Display for UnitError {
+    /// Display a human readable description of the error
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnknownUnit(input) => write!(f, "Invalid unit: unknown unit \"{input}\""),
+            Self::AmbiguousUnit { input, candidates } => {
+                let candidates: Vec<String> = candidates
+                    .iter()
+                    .map(|unit| format!("{unit} ({})", unit.dimension()))
+                    .collect();
+                write!(f, "Invalid unit: \"{input}\" is ambiguous, it could be {}", candidates.join(" or "))
+            }
+            Self::DimensionMismatch { expected, found } => {
+                write!(f, "Invalid unit: expected a {expected} unit, found a {found} unit")
+            }
+            Self::InvalidSyntax { input, reason } => {
+                write!(f, "Invalid syntax in \"{input}\": {reason}")
+            }
+            Self::ReferenceMismatch { expected, found } => {
+                write!(f, "Invalid bearing: expected a {expected} bearing, found a {found} bearing")
+            }
+            Self::KindMismatch { expected, found } => {
+                write!(f, "Invalid quantity: expected a quantity of kind {expected}, found {found}")
+            }
+            Self::NonFinite(value) => write!(f, "Invalid value: {value} is not finite"),
+            Self::OutOfDomain { value, unit } => {
+                write!(f, "Invalid value: {value} {unit} is out of range for the unit")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UnitError {}
@@ -0,0 +1,663 @@
+use crate::dimension::Dimension;
+use crate::error::UnitError;
+use crate::system::UnitSystem;
+use crate::units::*;
+use crate::values::Value;
+
+/// How the unit is written after the number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    /// the abbreviation, as in "2 ft"
+    #[default]
+    Abbreviation,
+    /// the name, singular for exactly one, as in "1 Foot" and "2 Feet"
+    Name,
+}
+
+/// ValueFormat struct
+///
+/// Options for writing a value as text: the digits of the number, and how the
+/// unit follows it. Built by chaining options onto `ValueFormat::new()`.
+///
+/// The `Display` impls of the values use the same options from the format
+/// spec: the precision sets the digits after the decimal point, and the
+/// alternate flag (`{:#}`) writes the unit name instead of the abbreviation.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::format::*;
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let length = LengthValue::new(1.0, &LengthUnit::Feet);
+/// let format = ValueFormat::new().precision(1).unit_style(UnitStyle::Name);
+/// assert_eq!(format.format(&length, &LengthUnit::Feet), "1.0 Foot");
+/// assert_eq!(format.format(&(length * 2.0), &LengthUnit::Feet), "2.0 Feet");
+///
+/// let format = ValueFormat::new().significant_figures(3).space(false);
+/// assert_eq!(format.format(&length, &LengthUnit::Meters), "0.305m");
+/// assert_eq!(format!("{:.1}", LengthValue::new(2.5, &LengthUnit::Kilometers)), "2500.0 m");
+///
+/// let board = LengthValue::new(3.6, &LengthUnit::Inches);
+/// assert_eq!(ValueFormat::new().fraction(16).format(&board, &LengthUnit::Inches), "3 5/8 in");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueFormat {
+    /// digits after the decimal point, or None for as many as needed
+    precision: Option<usize>,
+    /// significant figures, used instead of the precision when set
+    significant_figures: Option<usize>,
+    /// how the unit is written
+    unit_style: UnitStyle,
+    /// whether a space separates the number from the unit
+    space: bool,
+    /// whether to drop zeros at the end of the decimals
+    trim_zeros: bool,
+    /// denominator of the fraction to write the number with, used instead of the decimals when set
+    denominator: Option<u32>,
+}
+
+/// Implementing Default trait for ValueFormat
+/// Same as `ValueFormat::new`.
+impl Default for ValueFormat {
+    fn default() -> Self {
+        ValueFormat::new()
+    }
+}
+
+impl ValueFormat {
+    /// Create a new ValueFormat, writing the shortest number that reads back exactly,
+    /// a space, and the abbreviation of the unit.
+    pub const fn new() -> ValueFormat {
+        ValueFormat {
+            precision: None,
+            significant_figures: None,
+            unit_style: UnitStyle::Abbreviation,
+            space: true,
+            trim_zeros: false,
+            denominator: None,
+        }
+    }
+
+    /// Create a new ValueFormat from the flags of a format spec:
+    /// the precision, and the alternate flag for the unit name.
+    pub fn from_formatter(f: &std::fmt::Formatter) -> ValueFormat {
+        let unit_style = if f.alternate() { UnitStyle::Name } else { UnitStyle::Abbreviation };
+        ValueFormat {
+            precision: f.precision(),
+            ..ValueFormat::new()
+        }
+        .unit_style(unit_style)
+    }
+
+    /// Write `digits` digits after the decimal point.
+    /// Replaces any significant figures or fraction set before.
+    pub const fn precision(self, digits: usize) -> ValueFormat {
+        ValueFormat {
+            precision: Some(digits),
+            significant_figures: None,
+            denominator: None,
+            ..self
+        }
+    }
+
+    /// Round the number to `figures` significant figures, at least one.
+    /// Replaces any precision or fraction set before.
+    pub const fn significant_figures(self, figures: usize) -> ValueFormat {
+        ValueFormat {
+            precision: None,
+            significant_figures: Some(if figures == 0 { 1 } else { figures }),
+            denominator: None,
+            ..self
+        }
+    }
+
+    /// Write the number as a whole number and a reduced fraction, rounded to
+    /// the nearest 1/`denominator`, as in "3 5/8" to the nearest 1/16.
+    /// Replaces any precision or significant figures set before.
+    pub const fn fraction(self, denominator: u32) -> ValueFormat {
+        ValueFormat {
+            precision: None,
+            significant_figures: None,
+            denominator: Some(if denominator == 0 { 1 } else { denominator }),
+            ..self
+        }
+    }
+
+    /// Write the unit as its abbreviation or its name.
+    pub const fn unit_style(self, unit_style: UnitStyle) -> ValueFormat {
+        ValueFormat { unit_style, ..self }
+    }
+
+    /// Separate the number from the unit with a space, or not.
+    /// A degree sign never has a space before it, as in "45°".
+    pub const fn space(self, space: bool) -> ValueFormat {
+        ValueFormat { space, ..self }
+    }
+
+    /// Drop zeros at the end of the decimals, and the decimal point if none are left,
+    /// so 0.420 is written "0.42" and 2.00 is written "2".
+    pub const fn trim_zeros(self, trim_zeros: bool) -> ValueFormat {
+        ValueFormat { trim_zeros, ..self }
+    }
+
+    /// Write just the number, with the precision or significant figures of the format.
+    /// Example: 0.30479 with 3 significant figures -> "0.305"
+    pub fn format_number(&self, number: f64) -> String {
+        let text = match (self.denominator, self.significant_figures, self.precision) {
+            (Some(denominator), _, _) => format_fraction(number, denominator),
+            (None, Some(figures), _) => format_significant(number, figures),
+            (None, None, Some(digits)) => format!("{number:.digits$}"),
+            (None, None, None) => format!("{number}"),
+        };
+        let text = if self.trim_zeros && text.contains('.') {
+            String::from(text.trim_end_matches('0').trim_end_matches('.'))
+        } else {
+            text
+        };
+        // rounding a small negative number to zero leaves a sign behind
+        match text.strip_prefix('-') {
+            Some(rest) if rest.chars().all(|c| c == '0' || c == '.') => String::from(rest),
+            _ => text,
+        }
+    }
+
+    /// Write a number already in `unit`, followed by the unit.
+    /// Example: 2.0 Feet -> "2 ft"
+    pub fn format_in(&self, number: f64, unit: &UnitEnum) -> String {
+        let number = self.format_number(number);
+        // singular when the number as written is one
+        let one = number.parse::<f64>().is_ok_and(|n| n.abs() == 1.0);
+        let unit_text = match self.unit_style {
+            // abbreviations that are whole words, such as "days", follow the number too
+            UnitStyle::Abbreviation if one && unit.abbr() == unit.to_string().to_lowercase() => {
+                unit.name_singular().to_lowercase()
+            }
+            UnitStyle::Abbreviation => unit.abbr(),
+            UnitStyle::Name if one => unit.name_singular(),
+            UnitStyle::Name => unit.to_string(),
+        };
+        let separator = if self.space && unit_text != "°" { " " } else { "" };
+        format!("{number}{separator}{unit_text}")
+    }
+
+    /// Write a value in the given unit.
+    ///
+    /// Returns an error if the value cannot be converted to the unit.
+    pub fn try_format<V: Value>(&self, value: &V, unit: &V::Unit) -> Result<String, UnitError> {
+        Ok(self.format_in(value.try_value_in(unit)?, &unit.unit_enum()))
+    }
+
+    /// Write a value in the given unit.
+    /// Panicking wrapper around `try_format`.
+    pub fn format<V: Value>(&self, value: &V, unit: &V::Unit) -> String {
+        self.try_format(value, unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+// ---------------------------------------------------------
+
+/// Humanize struct
+///
+/// Options for writing a value in the most readable unit of a unit system,
+/// without picking the unit by hand.
+///
+/// The unit chosen is the largest unit of the system that puts the number
+/// within the magnitude range, by default [1, 1000), or else the unit that
+/// comes closest to it. Durations are split over up to four units by default,
+/// as in "1 day 2 hr 3 min 4 s", stopping at a unit that is a power of ten
+/// smaller, such as milliseconds; other values use a single unit.
+/// Gauge units are never chosen, since they are measured from an arbitrary reference.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::format::*;
+/// use units_and_values::system::*;
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// assert_eq!(TimeValue::from_si_value(93784.0).humanize(), "1 day 2 hr 3 min 4 s");
+/// assert_eq!(LengthValue::from_si_value(0.00042).humanize(), "0.42 mm");
+///
+/// let customary = Humanize::new().system(UnitSystem::us_customary());
+/// let height = LengthValue::new(30000.0, &LengthUnit::Feet);
+/// assert_eq!(height.humanize_with(&customary), "5.68 mi");
+/// assert_eq!(height.humanize_with(&customary.range(100.0, 100000.0)), "30000 ft");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Humanize {
+    /// units to choose from
+    system: UnitSystem,
+    /// smallest number that reads well
+    min: f64,
+    /// number above the largest that reads well
+    max: f64,
+    /// most units to split the value over, or None for the default of the dimension
+    parts: Option<usize>,
+    /// how to write the numbers and units
+    format: ValueFormat,
+}
+
+/// Implementing Default trait for Humanize
+/// Same as `Humanize::new`.
+impl Default for Humanize {
+    fn default() -> Self {
+        Humanize::new()
+    }
+}
+
+impl Humanize {
+    /// Create a new Humanize, choosing metric units for numbers in [1, 1000),
+    /// written to 3 significant figures without trailing zeros.
+    pub fn new() -> Humanize {
+        Humanize {
+            system: UnitSystem::metric(),
+            min: 1.0,
+            max: 1000.0,
+            parts: None,
+            format: ValueFormat::new().significant_figures(3).trim_zeros(true),
+        }
+    }
+
+    /// Choose from the units of the given system.
+    pub fn system(self, system: UnitSystem) -> Humanize {
+        Humanize { system, ..self }
+    }
+
+    /// Prefer units that put the number in [min, max).
+    pub fn range(self, min: f64, max: f64) -> Humanize {
+        Humanize { min, max, ..self }
+    }
+
+    /// Split the value over up to `parts` units, largest first, as in "1 hr 23 min".
+    /// One part writes a single unit.
+    pub fn parts(self, parts: usize) -> Humanize {
+        Humanize { parts: Some(parts.max(1)), ..self }
+    }
+
+    /// Write the numbers and units with the given format.
+    /// A value split over several units rounds its last part to the precision
+    /// of the format, or to a whole number if it has none.
+    pub fn value_format(self, format: ValueFormat) -> Humanize {
+        Humanize { format, ..self }
+    }
+
+    /// Get the unit a value would be written in, or the largest unit if it is split.
+    pub fn unit_for<V: Value>(&self, value: &V) -> UnitEnum {
+        let units = self.candidates(V::unit_default().dimension());
+        best_unit(&units, value.si_value().abs(), self.min, self.max)
+    }
+
+    /// Write a value in the most readable unit.
+    ///
+    /// Returns an error if the value is not finite.
+    pub fn try_format<V: Value>(&self, value: &V) -> Result<String, UnitError> {
+        let dimension = V::unit_default().dimension();
+        let units = self.candidates(dimension);
+        let best = best_unit(&units, value.si_value().abs(), self.min, self.max);
+        let parts = self.parts.unwrap_or(if dimension == Dimension::TIME { 4 } else { 1 });
+        // the best unit and the smaller ones, one of each size, down to the first
+        // that is a power of ten smaller, which decimals write just as well
+        let mut smaller: Vec<UnitEnum> = units.into_iter().filter(|unit| unit.scale() < best.scale()).collect();
+        smaller.sort_by(|a, b| b.scale().total_cmp(&a.scale()));
+        let mut split = vec![best];
+        for unit in smaller {
+            let ratio = split[split.len() - 1].scale() / unit.scale();
+            if split.len() == parts || (ratio.log10() - ratio.log10().round()).abs() < 1e-9 {
+                break;
+            }
+            split.push(unit);
+        }
+        if split.len() == 1 {
+            return Ok(self.format.format_in(value.try_value_in_unit(&best)?, &best));
+        }
+        let smallest = split[split.len() - 1];
+        Ok(format_split(value.try_value_in_unit(&smallest)?, &split, &self.format))
+    }
+
+    /// Write a value in the most readable unit.
+    /// Panicking wrapper around `try_format`.
+    pub fn format<V: Value>(&self, value: &V) -> String {
+        self.try_format(value)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Get the units of the system to choose from for the dimension.
+    fn candidates(&self, dimension: Dimension) -> Vec<UnitEnum> {
+        let units = self.system.units_of(dimension);
+        let absolute: Vec<UnitEnum> = units.iter().copied().filter(|unit| !unit.si_conversion().gauge).collect();
+        if absolute.is_empty() {
+            units
+        } else {
+            absolute
+        }
+    }
+}
+
+// ---------------------------------------------------------
+
+/// Compound struct
+///
+/// A chosen list of units to write a value split over, largest first,
+/// as in "5 ft 11 in", "1 hr 23 min" or "3 lbm 4 oz". Whole units carry up
+/// into the larger ones, parts that are zero are left out, and the last part
+/// is rounded to the precision or fraction of the format, or to a whole number.
+///
+/// The compound forms parse back with `Quantity::parse`, as do the
+/// shorthands `5'11"` and `1h23m`. Bearings split into degrees, arc minutes
+/// and arc seconds, as in "45° 30 arcmin 15 arcsec"; `BearingValue::format_dms`
+/// writes the same split with the usual symbols, as in `45°30'15"`.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::format::*;
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let height = LengthValue::new(71.4, &LengthUnit::Inches);
+/// assert_eq!(height.format_compound(&[LengthUnit::Feet, LengthUnit::Inches]), "5 ft 11 in");
+///
+/// let feet_inches = Compound::new(&[UnitEnum::Length(LengthUnit::Feet), UnitEnum::Length(LengthUnit::Inches)]);
+/// let format = feet_inches.value_format(ValueFormat::new().precision(1));
+/// assert_eq!(format.format(&height), "5 ft 11.4 in");
+/// assert_eq!("5 ft 11.4 in".parse::<LengthValue>().unwrap(), height);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Compound {
+    /// units to split the value over, largest first
+    units: Vec<UnitEnum>,
+    /// how to write the numbers and units
+    format: ValueFormat,
+}
+
+impl Compound {
+    /// Create a new Compound over the given units, in any order.
+    ///
+    /// Returns an error if there are no units, if they measure different
+    /// dimensions, or if any has an offset, as the temperature scales do.
+    pub fn try_new(units: &[UnitEnum]) -> Result<Compound, UnitError> {
+        let invalid = |reason: &str| UnitError::InvalidSyntax {
+            input: units.iter().map(|unit| unit.abbr()).collect::<Vec<String>>().join(" "),
+            reason: String::from(reason),
+        };
+        let first = units.first().ok_or_else(|| invalid("expected at least one unit"))?;
+        if let Some(other) = units.iter().find(|unit| unit.dimension() != first.dimension()) {
+            return Err(UnitError::DimensionMismatch { expected: first.dimension(), found: other.dimension() });
+        }
+        if units.iter().any(|unit| unit.si_conversion().offset != 0.0) {
+            return Err(invalid("units with an offset cannot be split"));
+        }
+        let mut units = units.to_vec();
+        units.sort_by(|a, b| b.scale().total_cmp(&a.scale()));
+        units.dedup_by(|a, b| a.scale() == b.scale());
+        Ok(Compound { units, format: ValueFormat::new() })
+    }
+
+    /// Create a new Compound over the given units, in any order.
+    /// Panicking wrapper around `try_new`.
+    pub fn new(units: &[UnitEnum]) -> Compound {
+        Self::try_new(units)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Write the numbers and units with the given format.
+    /// Only the precision or fraction applies to the last part; the others are whole numbers.
+    pub fn value_format(self, format: ValueFormat) -> Compound {
+        Compound { format, ..self }
+    }
+
+    /// Get the units, largest first.
+    pub fn units(&self) -> &[UnitEnum] {
+        &self.units
+    }
+
+    /// Write a value split over the units.
+    ///
+    /// Returns an error if the value has a different dimension than the units,
+    /// or is not finite.
+    pub fn try_format<V: Value>(&self, value: &V) -> Result<String, UnitError> {
+        let smallest = self.units[self.units.len() - 1];
+        Ok(format_split(value.try_value_in_unit(&smallest)?, &self.units, &self.format))
+    }
+
+    /// Write a value split over the units.
+    /// Panicking wrapper around `try_format`.
+    pub fn format<V: Value>(&self, value: &V) -> String {
+        self.try_format(value)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Choose the largest unit that puts `magnitude` (in the SI unit) in [min, max),
+/// or else the unit closest to it by ratio. Units of the same size keep their order.
+/// Zero and non-finite magnitudes use the first unit.
+fn best_unit(units: &[UnitEnum], magnitude: f64, min: f64, max: f64) -> UnitEnum {
+    let distance = |unit: &UnitEnum| {
+        let number = magnitude / unit.scale();
+        if number < min {
+            (min / number).log10()
+        } else if number >= max {
+            (number / max).log10()
+        } else {
+            0.0
+        }
+    };
+    let mut best = units[0];
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return best;
+    }
+    let mut best_distance = distance(&best);
+    for unit in &units[1..] {
+        let unit_distance = distance(unit);
+        if unit_distance < best_distance || (unit_distance == best_distance && unit.scale() > best.scale()) {
+            best = *unit;
+            best_distance = unit_distance;
+        }
+    }
+    best
+}
+
+/// Write a number in the last of `units` split over all of them, largest first,
+/// carrying whole units up and leaving out the parts that are zero.
+/// The last part is rounded to the precision or fraction of the format, or to a whole number.
+/// Example: 93784 s over [days, hr, min, s] -> "1 day 2 hr 3 min 4 s"
+pub(crate) fn format_split(number: f64, units: &[UnitEnum], format: &ValueFormat) -> String {
+    let smallest = units[units.len() - 1];
+    let whole = format.precision(0);
+    let (last, step) = match format.denominator {
+        Some(denominator) => (*format, f64::from(denominator)),
+        None => {
+            let decimals = format.precision.unwrap_or(0);
+            (format.precision(decimals), 10f64.powi(decimals as i32))
+        }
+    };
+
+    // count in steps of the last decimal or fraction, so the carry is exact
+    let mut rest = (number.abs() * step).round();
+    let mut parts = Vec::new();
+    for unit in &units[..units.len() - 1] {
+        let ratio = unit.scale() / smallest.scale() * step;
+        // ratios such as 1000 / 0.001 come out a hair off a whole number
+        let ratio = if (ratio - ratio.round()).abs() < 1e-9 * ratio { ratio.round() } else { ratio };
+        let count = (rest / ratio).floor();
+        if count > 0.0 {
+            parts.push(whole.format_in(count, unit));
+            rest -= count * ratio;
+        }
+    }
+    if rest > 0.0 || parts.is_empty() {
+        parts.push(last.format_in(rest / step, &smallest));
+    }
+    let sign = if number < 0.0 && parts.iter().any(|part| part.chars().any(|c| c.is_ascii_digit() && c != '0')) { "-" } else { "" };
+    format!("{sign}{}", parts.join(" "))
+}
+
+/// Write a number as a whole number and a reduced fraction of `denominator`.
+/// Example: (3.6, 16) -> "3 5/8", (0.5, 16) -> "1/2", (-2.0, 16) -> "-2"
+fn format_fraction(number: f64, denominator: u32) -> String {
+    let denominator = u64::from(denominator);
+    let steps = (number.abs() * denominator as f64).round() as u64;
+    let (whole, numerator) = (steps / denominator, steps % denominator);
+    let sign = if number < 0.0 && steps > 0 { "-" } else { "" };
+    if numerator == 0 {
+        return format!("{sign}{whole}");
+    }
+    let divisor = greatest_common_divisor(numerator, denominator);
+    let fraction = format!("{}/{}", numerator / divisor, denominator / divisor);
+    if whole == 0 {
+        format!("{sign}{fraction}")
+    } else {
+        format!("{sign}{whole} {fraction}")
+    }
+}
+
+/// Get the greatest common divisor of two numbers.
+fn greatest_common_divisor(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { greatest_common_divisor(b, a % b) }
+}
+
+/// Write a number rounded to `figures` significant figures.
+/// Example: (1234.5, 2) -> "1200", (0.012345, 2) -> "0.012"
+fn format_significant(number: f64, figures: usize) -> String {
+    if number == 0.0 || !number.is_finite() {
+        return format!("{number:.0$}", figures - 1);
+    }
+    // round in scientific notation first, so 9.996 to 3 figures becomes 10.0 and not 10.00
+    let scientific = format!("{number:.0$e}", figures - 1);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i64 = exponent.parse().unwrap_or(0);
+    if exponent + 1 >= figures as i64 {
+        // fill the places past the figures with zeros, not with the binary expansion of the number
+        let sign = if number < 0.0 { "-" } else { "" };
+        let digits: String = mantissa.chars().filter(|c| c.is_ascii_digit()).collect();
+        return format!("{sign}{digits}{}", "0".repeat((exponent + 1) as usize - figures));
+    }
+    let rounded: f64 = scientific.parse().unwrap_or(number);
+    let decimals = (figures as i64 - 1 - exponent) as usize;
+    format!("{rounded:.decimals$}")
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bearing::BearingValue;
+    use crate::values::*;
+
+    #[test]
+    fn test_format_number() {
+        let format = ValueFormat::new();
+        assert_eq!(format.format_number(0.1 + 0.2), "0.30000000000000004");
+        assert_eq!(format.precision(2).format_number(1.23456), "1.23");
+        assert_eq!(format.precision(1).format_number(-0.01), "0.0");
+        assert_eq!(format.significant_figures(3).format_number(1234.5), "1230");
+        assert_eq!(format.significant_figures(3).format_number(0.0012345), "0.00123");
+        assert_eq!(format.significant_figures(3).format_number(9.996), "10.0");
+        assert_eq!(format.significant_figures(3).format_number(999.6), "1000");
+        assert_eq!(format.significant_figures(3).format_number(-1.23456e20), "-123000000000000000000");
+        assert_eq!(format.significant_figures(3).format_number(1e30), format!("1{}", "0".repeat(30)));
+        assert_eq!(format.significant_figures(2).format_number(0.0), "0.0");
+        assert_eq!(format.significant_figures(2).precision(1).format_number(12.34), "12.3");
+        assert_eq!(format.precision(3).trim_zeros(true).format_number(2.5), "2.5");
+        assert_eq!(format.precision(3).trim_zeros(true).format_number(2.0), "2");
+        assert_eq!(format.fraction(16).format_number(3.6), "3 5/8");
+        assert_eq!(format.fraction(32).format_number(3.6), "3 19/32");
+        assert_eq!(format.fraction(16).format_number(0.5), "1/2");
+        assert_eq!(format.fraction(16).format_number(-1.999), "-2");
+        assert_eq!(format.fraction(16).format_number(-0.01), "0");
+        assert_eq!(format.fraction(16).precision(1).format_number(3.6), "3.6");
+    }
+
+    #[test]
+    fn test_format_value() {
+        let distance = LengthValue::new(2.0, &LengthUnit::NauticalMiles);
+        assert_eq!(ValueFormat::new().format(&distance, &LengthUnit::NauticalMiles), "2 Nmi");
+        let named = ValueFormat::new().unit_style(UnitStyle::Name);
+        assert_eq!(named.format(&distance, &LengthUnit::NauticalMiles), "2 Nautical Miles");
+        assert_eq!(named.format(&(distance / 2.0), &LengthUnit::NauticalMiles), "1 Nautical Mile");
+        assert_eq!(named.precision(2).format(&(distance / 2.0), &LengthUnit::NauticalMiles), "1.00 Nautical Mile");
+        assert_eq!(ValueFormat::new().space(false).format(&distance, &LengthUnit::Meters), "3704m");
+
+        let pressure = PressureValue::new(1.0, &PressureUnit::InchesOfMercury);
+        assert_eq!(named.format(&pressure, &PressureUnit::InchesOfMercury), "1 Inch Of Mercury");
+        let temperature = TemperatureValue::new(20.0, &TemperatureUnit::Celcius);
+        assert_eq!(ValueFormat::new().precision(1).format(&temperature, &TemperatureUnit::Celcius), "20.0 °C");
+        assert!(ValueFormat::new().try_format(&temperature, &TemperatureUnit::Celcius).is_ok());
+
+        let time = TimeValue::new(1.0, &TimeUnit::Days);
+        assert_eq!(ValueFormat::new().format(&time, &TimeUnit::Days), "1 day");
+        assert_eq!(ValueFormat::new().format(&(time * 2.0), &TimeUnit::Days), "2 days");
+    }
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!(TimeValue::from_si_value(93784.0).humanize(), "1 day 2 hr 3 min 4 s");
+        assert_eq!(TimeValue::from_si_value(7200.0).humanize(), "2 hr");
+        assert_eq!(TimeValue::from_si_value(3659.6).humanize(), "1 hr 1 min");
+        assert_eq!(TimeValue::from_si_value(-90.0).humanize(), "-1 min 30 s");
+        assert_eq!(TimeValue::from_si_value(0.0042).humanize(), "4.2 ms");
+        assert_eq!(TimeValue::from_si_value(93784.0).humanize_with(&Humanize::new().parts(1)), "1.09 days");
+        assert_eq!(LengthValue::from_si_value(0.00042).humanize(), "0.42 mm");
+        assert_eq!(LengthValue::from_si_value(1234.5).humanize(), "1.23 km");
+        assert_eq!(LengthValue::from_si_value(1234.5).humanize_with(&Humanize::new().parts(3)), "1.23 km");
+        assert_eq!(LengthValue::from_si_value(0.0).humanize(), "0 m");
+        assert_eq!(MassValue::new(2.0, &MassUnit::PoundsMass).humanize(), "907 g");
+
+        let customary = Humanize::new().system(UnitSystem::us_customary());
+        assert_eq!(LengthValue::new(0.5, &LengthUnit::Feet).humanize_with(&customary), "6 in");
+        assert_eq!(LengthValue::new(70.0, &LengthUnit::Inches).humanize_with(&customary.clone().parts(2)), "5 ft 10 in");
+        assert_eq!(customary.unit_for(&LengthValue::new(2.0, &LengthUnit::Miles)), UnitEnum::Length(LengthUnit::Miles));
+
+        let temperature = TemperatureValue::new(20.0, &TemperatureUnit::Celcius);
+        assert_eq!(temperature.humanize(), "20 °C");
+        assert_eq!(temperature.humanize_with(&customary), "68 °F");
+        assert_eq!(BearingValue::new(270.0, &BearingUnit::Degrees).humanize(), "270°");
+        assert_eq!(LengthValue::from_si_value(1e30).humanize(), format!("1{} km", "0".repeat(27)));
+        assert_eq!(AbsolutePressure::new(1.0, &PressureUnit::Atmospheres).humanize(), "101 kPa");
+    }
+
+    #[test]
+    fn test_compound() {
+        let feet_inches = [LengthUnit::Feet, LengthUnit::Inches];
+        assert_eq!(LengthValue::new(71.0, &LengthUnit::Inches).format_compound(&feet_inches), "5 ft 11 in");
+        assert_eq!(LengthValue::new(71.6, &LengthUnit::Inches).format_compound(&feet_inches), "6 ft");
+        assert_eq!(LengthValue::new(-13.0, &LengthUnit::Inches).format_compound(&feet_inches), "-1 ft 1 in");
+        assert_eq!(LengthValue::new(0.2, &LengthUnit::Inches).format_compound(&feet_inches), "0 in");
+        assert_eq!(TimeValue::new(83.0, &TimeUnit::Minutes).format_compound(&[TimeUnit::Minutes, TimeUnit::Hours]), "1 hr 23 min");
+        assert_eq!(MassValue::new(3.25, &MassUnit::PoundsMass).format_compound(&[MassUnit::PoundsMass, MassUnit::Ounces]), "3 lbm 4 oz");
+        assert_eq!(Elevation::new(1000.5, &LengthUnit::Meters).format_compound(&[LengthUnit::Kilometers, LengthUnit::Meters]), "1 km 1 m");
+
+        let dms = [BearingUnit::Degrees, BearingUnit::ArcMinutes, BearingUnit::ArcSeconds];
+        assert_eq!(BearingValue::new(45.0 + 30.0 / 60.0 + 15.0 / 3600.0, &BearingUnit::Degrees).format_compound(&dms), "45° 30 arcmin 15 arcsec");
+        assert_eq!(BearingValue::new(12.75, &BearingUnit::Degrees).format_compound(&dms[..2]), "12° 45 arcmin");
+
+        let hours_minutes = Compound::new(&[UnitEnum::Time(TimeUnit::Hours), UnitEnum::Time(TimeUnit::Minutes)]);
+        let named = hours_minutes.clone().value_format(ValueFormat::new().precision(1).unit_style(UnitStyle::Name));
+        assert_eq!(named.format(&TimeValue::new(61.25, &TimeUnit::Minutes)), "1 Hour 1.3 Minutes");
+        assert_eq!(named.format(&TimeValue::new(59.96, &TimeUnit::Minutes)), "1 Hour");
+        assert!(hours_minutes.try_format(&LengthValue::from_si_value(1.0)).is_err());
+
+        let sixteenths = Compound::new(&[UnitEnum::Length(LengthUnit::Feet), UnitEnum::Length(LengthUnit::Inches)])
+            .value_format(ValueFormat::new().fraction(16));
+        assert_eq!(sixteenths.format(&LengthValue::new(71.6, &LengthUnit::Inches)), "5 ft 11 5/8 in");
+        assert_eq!(sixteenths.format(&LengthValue::new(71.99, &LengthUnit::Inches)), "6 ft");
+
+        assert!(Compound::try_new(&[]).is_err());
+        assert!(Compound::try_new(&[UnitEnum::Time(TimeUnit::Hours), UnitEnum::Length(LengthUnit::Feet)]).is_err());
+        assert!(Compound::try_new(&[UnitEnum::Temperature(TemperatureUnit::Celcius)]).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let speed = VelocityValue::new(36.0, &VelocityUnit::KilometersPerHour);
+        assert_eq!(format!("{speed}"), "10 m/s");
+        assert_eq!(format!("{speed:.2}"), "10.00 m/s");
+        assert_eq!(format!("{speed:#}"), "10 Meters Per Second");
+        assert_eq!(format!("{:#}", VelocityValue::from_si_value(1.0)), "1 Meter Per Second");
+        let temperature = TemperatureValue::new(0.0, &TemperatureUnit::Celcius);
+        assert_eq!(format!("{temperature:.1}"), "273.1 K");
+        let pressure = AbsolutePressure::new(1.0, &PressureUnit::Atmospheres);
+        assert_eq!(pressure.to_string(), "101325 Pa");
+    }
+}
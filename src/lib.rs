@@ -1,18 +1,11 @@
-#![allow(unused)]
+pub mod bearing;
+pub mod dimension;
+pub mod dynamic;
+pub mod error;
+pub mod format;
+pub mod magnetic;
+pub mod parse;
+pub mod preferences;
+pub mod system;
 pub mod units;
 pub mod values;
-
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
@@ -0,0 +1,593 @@
+use crate::error::UnitError;
+use crate::units::*;
+use crate::values::{AngleValue, LengthValue, Value};
+
+/// Gauss coefficients of WMM2025, the World Magnetic Model for 2025.0 to 2030.0,
+/// as (n, m, g, h, ġ, ḣ), in nT and nT per year,
+/// from the WMM.COF file published by NOAA NCEI on 11/13/2024.
+const WMM2025: &[(usize, usize, f64, f64, f64, f64)] = &[
+    (1, 0, -29351.8, 0.0, 12.0, 0.0),
+    (1, 1, -1410.8, 4545.4, 9.7, -21.5),
+    (2, 0, -2556.6, 0.0, -11.6, 0.0),
+    (2, 1, 2951.1, -3133.6, -5.2, -27.7),
+    (2, 2, 1649.3, -815.1, -8.0, -12.1),
+    (3, 0, 1361.0, 0.0, -1.3, 0.0),
+    (3, 1, -2404.1, -56.6, -4.2, 4.0),
+    (3, 2, 1243.8, 237.5, 0.4, -0.3),
+    (3, 3, 453.6, -549.5, -15.6, -4.1),
+    (4, 0, 895.0, 0.0, -1.6, 0.0),
+    (4, 1, 799.5, 278.6, -2.4, -1.1),
+    (4, 2, 55.7, -133.9, -6.0, 4.1),
+    (4, 3, -281.1, 212.0, 5.6, 1.6),
+    (4, 4, 12.1, -375.6, -7.0, -4.4),
+    (5, 0, -233.2, 0.0, 0.6, 0.0),
+    (5, 1, 368.9, 45.4, 1.4, -0.5),
+    (5, 2, 187.2, 220.2, 0.0, 2.2),
+    (5, 3, -138.7, -122.9, 0.6, 0.4),
+    (5, 4, -142.0, 43.0, 2.2, 1.7),
+    (5, 5, 20.9, 106.1, 0.9, 1.9),
+    (6, 0, 64.4, 0.0, -0.2, 0.0),
+    (6, 1, 63.8, -18.4, -0.4, 0.3),
+    (6, 2, 76.9, 16.8, 0.9, -1.6),
+    (6, 3, -115.7, 48.8, 1.2, -0.4),
+    (6, 4, -40.9, -59.8, -0.9, 0.9),
+    (6, 5, 14.9, 10.9, 0.3, 0.7),
+    (6, 6, -60.7, 72.7, 0.9, 0.9),
+    (7, 0, 79.5, 0.0, -0.0, 0.0),
+    (7, 1, -77.0, -48.9, -0.1, 0.6),
+    (7, 2, -8.8, -14.4, -0.1, 0.5),
+    (7, 3, 59.3, -1.0, 0.5, -0.8),
+    (7, 4, 15.8, 23.4, -0.1, 0.0),
+    (7, 5, 2.5, -7.4, -0.8, -1.0),
+    (7, 6, -11.1, -25.1, -0.8, 0.6),
+    (7, 7, 14.2, -2.3, 0.8, -0.2),
+    (8, 0, 23.2, 0.0, -0.1, 0.0),
+    (8, 1, 10.8, 7.1, 0.2, -0.2),
+    (8, 2, -17.5, -12.6, 0.0, 0.5),
+    (8, 3, 2.0, 11.4, 0.5, -0.4),
+    (8, 4, -21.7, -9.7, -0.1, 0.4),
+    (8, 5, 16.9, 12.7, 0.3, -0.5),
+    (8, 6, 15.0, 0.7, 0.2, -0.6),
+    (8, 7, -16.8, -5.2, -0.0, 0.3),
+    (8, 8, 0.9, 3.9, 0.2, 0.2),
+    (9, 0, 4.6, 0.0, -0.0, 0.0),
+    (9, 1, 7.8, -24.8, -0.1, -0.3),
+    (9, 2, 3.0, 12.2, 0.1, 0.3),
+    (9, 3, -0.2, 8.3, 0.3, -0.3),
+    (9, 4, -2.5, -3.3, -0.3, 0.3),
+    (9, 5, -13.1, -5.2, 0.0, 0.2),
+    (9, 6, 2.4, 7.2, 0.3, -0.1),
+    (9, 7, 8.6, -0.6, -0.1, -0.2),
+    (9, 8, -8.7, 0.8, 0.1, 0.4),
+    (9, 9, -12.9, 10.0, -0.1, 0.1),
+    (10, 0, -1.3, 0.0, 0.1, 0.0),
+    (10, 1, -6.4, 3.3, 0.0, 0.0),
+    (10, 2, 0.2, 0.0, 0.1, -0.0),
+    (10, 3, 2.0, 2.4, 0.1, -0.2),
+    (10, 4, -1.0, 5.3, -0.0, 0.1),
+    (10, 5, -0.6, -9.1, -0.3, -0.1),
+    (10, 6, -0.9, 0.4, 0.0, 0.1),
+    (10, 7, 1.5, -4.2, -0.1, 0.0),
+    (10, 8, 0.9, -3.8, -0.1, -0.1),
+    (10, 9, -2.7, 0.9, -0.0, 0.2),
+    (10, 10, -3.9, -9.1, -0.0, -0.0),
+    (11, 0, 2.9, 0.0, 0.0, 0.0),
+    (11, 1, -1.5, 0.0, -0.0, -0.0),
+    (11, 2, -2.5, 2.9, 0.0, 0.1),
+    (11, 3, 2.4, -0.6, 0.0, -0.0),
+    (11, 4, -0.6, 0.2, 0.0, 0.1),
+    (11, 5, -0.1, 0.5, -0.1, -0.0),
+    (11, 6, -0.6, -0.3, 0.0, -0.0),
+    (11, 7, -0.1, -1.2, -0.0, 0.1),
+    (11, 8, 1.1, -1.7, -0.1, -0.0),
+    (11, 9, -1.0, -2.9, -0.1, 0.0),
+    (11, 10, -0.2, -1.8, -0.1, 0.0),
+    (11, 11, 2.6, -2.3, -0.1, 0.0),
+    (12, 0, -2.0, 0.0, 0.0, 0.0),
+    (12, 1, -0.2, -1.3, 0.0, -0.0),
+    (12, 2, 0.3, 0.7, -0.0, 0.0),
+    (12, 3, 1.2, 1.0, -0.0, -0.1),
+    (12, 4, -1.3, -1.4, -0.0, 0.1),
+    (12, 5, 0.6, -0.0, -0.0, -0.0),
+    (12, 6, 0.6, 0.6, 0.1, -0.0),
+    (12, 7, 0.5, -0.1, -0.0, -0.0),
+    (12, 8, -0.1, 0.8, 0.0, 0.0),
+    (12, 9, -0.4, 0.1, 0.0, -0.0),
+    (12, 10, -0.2, -1.0, -0.1, -0.0),
+    (12, 11, -1.3, 0.1, -0.0, 0.0),
+    (12, 12, -0.7, 0.2, -0.1, -0.1),
+];
+
+/// Epoch of WMM2025, as a decimal year
+const WMM2025_EPOCH: f64 = 2025.0;
+
+/// Gauss coefficients of WMM2020, the World Magnetic Model for 2020.0 to 2025.0,
+/// as (n, m, g, h, ġ, ḣ), in nT and nT per year,
+/// from the WMM.COF file published by NOAA NCEI on 12/10/2019.
+const WMM2020: &[(usize, usize, f64, f64, f64, f64)] = &[
+    (1, 0, -29404.5, 0.0, 6.7, 0.0),
+    (1, 1, -1450.7, 4652.9, 7.7, -25.1),
+    (2, 0, -2500.0, 0.0, -11.5, 0.0),
+    (2, 1, 2982.0, -2991.6, -7.1, -30.2),
+    (2, 2, 1676.8, -734.8, -2.2, -23.9),
+    (3, 0, 1363.9, 0.0, 2.8, 0.0),
+    (3, 1, -2381.0, -82.2, -6.2, 5.7),
+    (3, 2, 1236.2, 241.8, 3.4, -1.0),
+    (3, 3, 525.7, -542.9, -12.2, 1.1),
+    (4, 0, 903.1, 0.0, -1.1, 0.0),
+    (4, 1, 809.4, 282.0, -1.6, 0.2),
+    (4, 2, 86.2, -158.4, -6.0, 6.9),
+    (4, 3, -309.4, 199.8, 5.4, 3.7),
+    (4, 4, 47.9, -350.1, -5.5, -5.6),
+    (5, 0, -234.4, 0.0, -0.3, 0.0),
+    (5, 1, 363.1, 47.7, 0.6, 0.1),
+    (5, 2, 187.8, 208.4, -0.7, 2.5),
+    (5, 3, -140.7, -121.3, 0.1, -0.9),
+    (5, 4, -151.2, 32.2, 1.2, 3.0),
+    (5, 5, 13.7, 99.1, 1.0, 0.5),
+    (6, 0, 65.9, 0.0, -0.6, 0.0),
+    (6, 1, 65.6, -19.1, -0.4, 0.1),
+    (6, 2, 73.0, 25.0, 0.5, -1.8),
+    (6, 3, -121.5, 52.7, 1.4, -1.4),
+    (6, 4, -36.2, -64.4, -1.4, 0.9),
+    (6, 5, 13.5, 9.0, 0.0, 0.1),
+    (6, 6, -64.7, 68.1, 0.8, 1.0),
+    (7, 0, 80.6, 0.0, -0.1, 0.0),
+    (7, 1, -76.8, -51.4, -0.3, 0.5),
+    (7, 2, -8.3, -16.8, -0.1, 0.6),
+    (7, 3, 56.5, 2.3, 0.7, -0.7),
+    (7, 4, 15.8, 23.5, 0.2, -0.2),
+    (7, 5, 6.4, -2.2, -0.5, -1.2),
+    (7, 6, -7.2, -27.2, -0.8, 0.2),
+    (7, 7, 9.8, -1.9, 1.0, 0.3),
+    (8, 0, 23.6, 0.0, -0.1, 0.0),
+    (8, 1, 9.8, 8.4, 0.1, -0.3),
+    (8, 2, -17.5, -15.3, -0.1, 0.7),
+    (8, 3, -0.4, 12.8, 0.5, -0.2),
+    (8, 4, -21.1, -11.8, -0.1, 0.5),
+    (8, 5, 15.3, 14.9, 0.4, -0.3),
+    (8, 6, 13.7, 3.6, 0.5, -0.5),
+    (8, 7, -16.5, -6.9, 0.0, 0.4),
+    (8, 8, -0.3, 2.8, 0.4, 0.1),
+    (9, 0, 5.0, 0.0, -0.1, 0.0),
+    (9, 1, 8.2, -23.3, -0.2, -0.3),
+    (9, 2, 2.9, 11.1, 0.0, 0.2),
+    (9, 3, -1.4, 9.8, 0.4, -0.4),
+    (9, 4, -1.1, -5.1, -0.3, 0.4),
+    (9, 5, -13.3, -6.2, 0.0, 0.1),
+    (9, 6, 1.1, 7.8, 0.3, 0.0),
+    (9, 7, 8.9, 0.4, 0.0, -0.2),
+    (9, 8, -9.3, -1.5, 0.0, 0.5),
+    (9, 9, -11.9, 9.7, -0.4, 0.2),
+    (10, 0, -1.9, 0.0, 0.0, 0.0),
+    (10, 1, -6.2, 3.4, 0.0, 0.0),
+    (10, 2, -0.1, -0.2, 0.0, 0.1),
+    (10, 3, 1.7, 3.5, 0.2, -0.3),
+    (10, 4, -0.9, 4.8, -0.1, 0.1),
+    (10, 5, 0.6, -8.6, -0.2, -0.2),
+    (10, 6, -0.9, -0.1, 0.0, 0.1),
+    (10, 7, 1.9, -4.2, -0.1, 0.0),
+    (10, 8, 1.4, -3.4, -0.2, -0.1),
+    (10, 9, -2.4, -0.1, -0.1, 0.2),
+    (10, 10, -3.9, -8.8, 0.0, 0.0),
+    (11, 0, 3.0, 0.0, 0.0, 0.0),
+    (11, 1, -1.4, 0.0, -0.1, 0.0),
+    (11, 2, -2.5, 2.6, 0.0, 0.1),
+    (11, 3, 2.4, -0.5, 0.0, 0.0),
+    (11, 4, -0.9, -0.4, 0.0, 0.2),
+    (11, 5, 0.3, 0.6, -0.1, 0.0),
+    (11, 6, -0.7, -0.2, 0.0, 0.0),
+    (11, 7, -0.1, -1.7, 0.0, 0.1),
+    (11, 8, 1.4, -1.6, -0.1, 0.0),
+    (11, 9, -0.6, -3.0, -0.1, -0.1),
+    (11, 10, 0.2, -2.0, -0.1, 0.0),
+    (11, 11, 3.1, -2.6, -0.1, 0.0),
+    (12, 0, -2.0, 0.0, 0.0, 0.0),
+    (12, 1, -0.1, -1.2, 0.0, 0.0),
+    (12, 2, 0.5, 0.5, 0.0, 0.0),
+    (12, 3, 1.3, 1.3, 0.0, -0.1),
+    (12, 4, -1.2, -1.8, 0.0, 0.1),
+    (12, 5, 0.7, 0.1, 0.0, 0.0),
+    (12, 6, 0.3, 0.7, 0.0, 0.0),
+    (12, 7, 0.5, -0.1, 0.0, 0.0),
+    (12, 8, -0.2, 0.6, 0.0, 0.1),
+    (12, 9, -0.5, 0.2, 0.0, 0.0),
+    (12, 10, 0.1, -0.9, 0.0, 0.0),
+    (12, 11, -1.1, 0.0, 0.0, 0.0),
+    (12, 12, -0.3, 0.5, -0.1, -0.1),
+];
+
+/// Epoch of WMM2020, as a decimal year
+const WMM2020_EPOCH: f64 = 2020.0;
+
+/// Years after its epoch that a World Magnetic Model stays valid
+const MODEL_LIFETIME: f64 = 5.0;
+
+/// Semi-major axis of the WGS 84 ellipsoid, in Kilometers
+const WGS84_A: f64 = 6378.137;
+/// Flattening of the WGS 84 ellipsoid
+const WGS84_F: f64 = 1.0 / 298.257223563;
+/// Reference radius of the geomagnetic field model, in Kilometers
+const GEOMAGNETIC_RADIUS: f64 = 6371.2;
+
+/// One Gauss coefficient of the spherical harmonic field model,
+/// for degree `n` and order `m`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GaussCoefficient {
+    n: usize,
+    m: usize,
+    /// g at the epoch, in nT
+    g: f64,
+    /// h at the epoch, in nT
+    h: f64,
+    /// secular variation of g, in nT per year
+    g_dot: f64,
+    /// secular variation of h, in nT per year
+    h_dot: f64,
+}
+
+/// MagneticModel struct
+///
+/// A spherical harmonic model of the Earth's main magnetic field, such as the
+/// World Magnetic Model, used to find the magnetic declination anywhere on Earth
+/// without a network connection.
+///
+/// A model is only valid for the five years after its epoch. WMM2025 and
+/// WMM2020 are built in, and `for_year` picks the one that covers a date;
+/// later models are published by NOAA as WMM.COF files and load with `from_cof`.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::magnetic::*;
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let model = MagneticModel::wmm2025();
+/// let declination = model.declination(
+///     AngleValue::new(80.0, &BearingUnit::Degrees),
+///     AngleValue::new(0.0, &BearingUnit::Degrees),
+///     LengthValue::new(0.0, &LengthUnit::Meters),
+///     2025.0,
+/// );
+/// assert!((declination.value_in(&BearingUnit::Degrees) - 1.28).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MagneticModel {
+    /// name of the model, such as "WMM-2025"
+    name: String,
+    /// epoch of the coefficients, as a decimal year
+    epoch: f64,
+    /// coefficients, in any order
+    coefficients: Vec<GaussCoefficient>,
+}
+
+impl MagneticModel {
+    /// Get the built in World Magnetic Model 2025, valid from 2025.0 to 2030.0.
+    pub fn wmm2025() -> MagneticModel {
+        MagneticModel::built_in("WMM-2025", WMM2025_EPOCH, WMM2025)
+    }
+
+    /// Get the built in World Magnetic Model 2020, valid from 2020.0 to 2025.0.
+    pub fn wmm2020() -> MagneticModel {
+        MagneticModel::built_in("WMM-2020", WMM2020_EPOCH, WMM2020)
+    }
+
+    /// Get the newest built in model that is valid in `year`, a decimal year.
+    /// Returns None if no built in model covers the year.
+    pub fn for_year(year: f64) -> Option<MagneticModel> {
+        [MagneticModel::wmm2025(), MagneticModel::wmm2020()]
+            .into_iter()
+            .find(|model| model.epoch <= year && year <= model.valid_until())
+    }
+
+    /// Create a built in model from its table of coefficients.
+    fn built_in(name: &str, epoch: f64, table: &[(usize, usize, f64, f64, f64, f64)]) -> MagneticModel {
+        MagneticModel {
+            name: String::from(name),
+            epoch,
+            coefficients: table
+                .iter()
+                .map(|&(n, m, g, h, g_dot, h_dot)| GaussCoefficient { n, m, g, h, g_dot, h_dot })
+                .collect(),
+        }
+    }
+
+    /// Load a model from the text of a WMM.COF coefficient file.
+    ///
+    /// The first line holds the epoch and the model name, each following line
+    /// holds `n m g h ġ ḣ`, and a line of 9s ends the coefficients.
+    ///
+    /// Returns an error naming the line if the text is not a coefficient file.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::magnetic::*;
+    ///
+    /// let text = "    2020.0            WMM-2020        12/10/2019\n  1  0  -29404.5       0.0        6.7        0.0\n";
+    /// let model = MagneticModel::from_cof(text).unwrap();
+    /// assert_eq!(model.name(), "WMM-2020");
+    /// assert_eq!(model.valid_until(), 2025.0);
+    /// ```
+    pub fn from_cof(text: &str) -> Result<MagneticModel, UnitError> {
+        let error = |line: usize, reason: &str| UnitError::InvalidSyntax {
+            input: String::from(text.lines().nth(line - 1).unwrap_or_default()),
+            reason: format!("line {line}: {reason}"),
+        };
+        let mut lines = text.lines().enumerate().map(|(index, line)| (index + 1, line));
+        let (_, header) = lines.next().ok_or_else(|| error(1, "expected the epoch and model name"))?;
+        let mut header_fields = header.split_whitespace();
+        let epoch = header_fields
+            .next()
+            .and_then(|field| field.parse::<f64>().ok())
+            .ok_or_else(|| error(1, "expected the epoch"))?;
+        let name = String::from(header_fields.next().ok_or_else(|| error(1, "expected the model name"))?);
+
+        let mut coefficients = Vec::new();
+        for (number, line) in lines {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with("9999") {
+                break;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 6 {
+                return Err(error(number, "expected n, m, g, h, and their secular variation"));
+            }
+            let n: usize = fields[0].parse().map_err(|_| error(number, "expected the degree n"))?;
+            let m: usize = fields[1].parse().map_err(|_| error(number, "expected the order m"))?;
+            if n == 0 || m > n {
+                return Err(error(number, "expected 0 <= m <= n and n >= 1"));
+            }
+            let mut values = [0.0; 4];
+            for (value, field) in values.iter_mut().zip(&fields[2..]) {
+                *value = field.parse().map_err(|_| error(number, "expected a number"))?;
+            }
+            let [g, h, g_dot, h_dot] = values;
+            coefficients.push(GaussCoefficient { n, m, g, h, g_dot, h_dot });
+        }
+        if coefficients.is_empty() {
+            return Err(error(1, "expected at least one coefficient"));
+        }
+        Ok(MagneticModel { name, epoch, coefficients })
+    }
+
+    /// Get the name of the model.
+    /// Example: "WMM-2025"
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the epoch of the model, as a decimal year.
+    pub fn epoch(&self) -> f64 {
+        self.epoch
+    }
+
+    /// Get the end of the model's validity, as a decimal year.
+    pub fn valid_until(&self) -> f64 {
+        self.epoch + MODEL_LIFETIME
+    }
+
+    /// Get the magnetic declination, the angle from true north to magnetic north,
+    /// positive to the east.
+    ///
+    /// `latitude` and `longitude` are geodetic (WGS 84), `altitude` is the height
+    /// above the WGS 84 ellipsoid, and `year` is a decimal year (see `decimal_year`).
+    ///
+    /// Returns an error if a value is not finite, if the latitude is not within ±90°,
+    /// at the geographic poles where the declination is undefined,
+    /// or if `year` is outside the validity of the model.
+    pub fn try_declination(&self, latitude: AngleValue, longitude: AngleValue, altitude: LengthValue, year: f64) -> Result<AngleValue, UnitError> {
+        for value in [latitude.si_value(), longitude.si_value(), altitude.si_value(), year] {
+            if !value.is_finite() {
+                return Err(UnitError::NonFinite(value));
+            }
+        }
+        if year < self.epoch || year > self.valid_until() {
+            return Err(UnitError::OutOfDomain {
+                value: year,
+                unit: format!("year for {} (valid from {} to {})", self.name, self.epoch, self.valid_until()),
+            });
+        }
+        let latitude_degrees = latitude.value_in(&BearingUnit::Degrees);
+        if latitude_degrees.abs() >= 90.0 {
+            return Err(UnitError::OutOfDomain {
+                value: latitude_degrees,
+                unit: String::from("° of latitude"),
+            });
+        }
+        let (north, east, _) = self.field(latitude.si_value(), longitude.si_value(), altitude.value_in(&LengthUnit::Kilometers), year);
+        Ok(AngleValue::from_si_value(east.atan2(north)))
+    }
+
+    /// Get the magnetic declination.
+    /// Panicking wrapper around `try_declination`.
+    pub fn declination(&self, latitude: AngleValue, longitude: AngleValue, altitude: LengthValue, year: f64) -> AngleValue {
+        self.try_declination(latitude, longitude, altitude, year)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Compute the north, east and down components of the field in nT,
+    /// at a geodetic latitude and longitude in Radians and a height in Kilometers.
+    fn field(&self, latitude: f64, longitude: f64, height: f64, year: f64) -> (f64, f64, f64) {
+        // geodetic to geocentric spherical coordinates
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let prime_vertical = WGS84_A / (1.0 - e2 * latitude.sin().powi(2)).sqrt();
+        let p = (prime_vertical + height) * latitude.cos();
+        let z = (prime_vertical * (1.0 - e2) + height) * latitude.sin();
+        let radius = p.hypot(z);
+        let geocentric_latitude = (z / radius).asin();
+
+        let degree = self.coefficients.iter().map(|c| c.n).max().unwrap_or(0);
+        let (legendre, derivative) = schmidt_legendre(degree, geocentric_latitude);
+        let (cos_latitude, dt) = (geocentric_latitude.cos(), year - self.epoch);
+
+        let (mut north, mut east, mut down) = (0.0, 0.0, 0.0);
+        for c in &self.coefficients {
+            let scale = (GEOMAGNETIC_RADIUS / radius).powi(c.n as i32 + 2);
+            let g = c.g + dt * c.g_dot;
+            let h = c.h + dt * c.h_dot;
+            let (sin_m, cos_m) = (c.m as f64 * longitude).sin_cos();
+            let index = c.n * (degree + 1) + c.m;
+            north += scale * (g * cos_m + h * sin_m) * derivative[index];
+            east += scale * c.m as f64 * (g * sin_m - h * cos_m) * legendre[index] / cos_latitude;
+            down -= scale * (c.n as f64 + 1.0) * (g * cos_m + h * sin_m) * legendre[index];
+        }
+
+        // rotate from the geocentric to the geodetic horizon
+        let (sin_psi, cos_psi) = (geocentric_latitude - latitude).sin_cos();
+        (north * cos_psi - down * sin_psi, east, north * sin_psi + down * cos_psi)
+    }
+}
+
+/// Compute the Schmidt semi-normalised associated Legendre functions P(n, m)
+/// of sin(latitude) up to `degree`, and their derivatives by colatitude.
+/// Both are indexed by `n * (degree + 1) + m`.
+fn schmidt_legendre(degree: usize, latitude: f64) -> (Vec<f64>, Vec<f64>) {
+    let (x, s) = latitude.sin_cos();
+    let size = degree + 1;
+    let mut p = vec![0.0; size * size];
+    let mut dp = vec![0.0; size * size];
+    p[0] = 1.0;
+    for n in 1..=degree {
+        for m in 0..=n {
+            let index = n * size + m;
+            if n == m {
+                let k = if n == 1 { 1.0 } else { ((2 * n - 1) as f64 / (2 * n) as f64).sqrt() };
+                let previous = (n - 1) * size + (n - 1);
+                p[index] = k * s * p[previous];
+                dp[index] = k * (s * dp[previous] + x * p[previous]);
+            } else {
+                let above = (n - 1) * size + m;
+                let (two_above, k) = if n >= 2 {
+                    ((n - 2) * size + m, (((n - 1) * (n - 1) - m * m) as f64).sqrt())
+                } else {
+                    (0, 0.0)
+                };
+                let denominator = ((n * n - m * m) as f64).sqrt();
+                let t = (2 * n - 1) as f64;
+                p[index] = (t * x * p[above] - k * p[two_above]) / denominator;
+                dp[index] = (t * (x * dp[above] - s * p[above]) - k * dp[two_above]) / denominator;
+            }
+        }
+    }
+    (p, dp)
+}
+
+/// Convert a calendar date to a decimal year, as the magnetic models expect.
+/// Example: 2 July 2024 (a leap year) -> 2024.5
+///
+/// Returns an error if the month or the day does not exist.
+pub fn decimal_year(year: i32, month: u32, day: u32) -> Result<f64, UnitError> {
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let month_lengths = [31, if leap { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let days_in_month = *month_lengths
+        .get((month as usize).wrapping_sub(1))
+        .ok_or(UnitError::OutOfDomain { value: f64::from(month), unit: String::from("month") })?;
+    if day == 0 || day > days_in_month {
+        return Err(UnitError::OutOfDomain { value: f64::from(day), unit: String::from("day of the month") });
+    }
+    let day_of_year: u32 = month_lengths[..month as usize - 1].iter().sum::<u32>() + day - 1;
+    let days_in_year = if leap { 366.0 } else { 365.0 };
+    Ok(f64::from(year) + f64::from(day_of_year) / days_in_year)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the declination in Degrees from the built in model for the year,
+    /// at a height in Kilometers
+    fn declination(latitude: f64, longitude: f64, height: f64, year: f64) -> f64 {
+        MagneticModel::for_year(year)
+            .unwrap()
+            .declination(
+                AngleValue::new(latitude, &BearingUnit::Degrees),
+                AngleValue::new(longitude, &BearingUnit::Degrees),
+                LengthValue::new(height, &LengthUnit::Kilometers),
+                year,
+            )
+            .value_in(&BearingUnit::Degrees)
+    }
+
+    #[test]
+    fn test_wmm2020_test_values() {
+        // test values published with WMM2020
+        assert!((declination(80.0, 0.0, 0.0, 2020.0) + 1.28).abs() < 0.01);
+        assert!((declination(0.0, 120.0, 0.0, 2020.0) - 0.16).abs() < 0.01);
+        assert!((declination(-80.0, 240.0, 0.0, 2020.0) - 69.36).abs() < 0.01);
+        assert!((declination(80.0, 0.0, 100.0, 2020.0) + 1.70).abs() < 0.01);
+        assert!((declination(-80.0, 240.0, 100.0, 2020.0) - 68.78).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_wmm2025_test_values() {
+        // test values published with WMM2025
+        assert!((declination(80.0, 0.0, 0.0, 2025.0) - 1.28).abs() < 0.01);
+        let (north, east, down) = MagneticModel::wmm2025().field(80f64.to_radians(), 0.0, 0.0, 2025.0);
+        assert!((north - 6521.5).abs() < 0.15);
+        assert!((east - 145.9).abs() < 0.1);
+        assert!((down - 54791.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_wmm2020_field() {
+        let model = MagneticModel::wmm2020();
+        let (north, east, down) = model.field(80f64.to_radians(), 0.0, 0.0, 2020.0);
+        assert!((north - 6570.4).abs() < 0.1);
+        assert!((east + 146.3).abs() < 0.1);
+        assert!((down - 54606.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_declination_domain() {
+        let model = MagneticModel::wmm2020();
+        let zero = AngleValue::from_si_value(0.0);
+        let sea_level = LengthValue::from_si_value(0.0);
+        assert!(model.try_declination(zero, zero, sea_level, 2019.9).is_err());
+        assert!(model.try_declination(zero, zero, sea_level, 2025.1).is_err());
+        let model = MagneticModel::wmm2025();
+        assert!(model.try_declination(zero, zero, sea_level, 2024.9).is_err());
+        assert!(model.try_declination(zero, zero, sea_level, 2026.8).is_ok());
+        assert!(model.try_declination(zero, zero, sea_level, 2030.1).is_err());
+        assert!(model.try_declination(zero, zero, sea_level, f64::NAN).is_err());
+        let pole = AngleValue::new(90.0, &BearingUnit::Degrees);
+        assert!(model.try_declination(pole, zero, sea_level, 2022.0).is_err());
+    }
+
+    #[test]
+    fn test_for_year() {
+        assert_eq!(MagneticModel::for_year(2022.0).map(|model| model.epoch()), Some(2020.0));
+        assert_eq!(MagneticModel::for_year(2025.0).map(|model| model.epoch()), Some(2025.0));
+        assert_eq!(MagneticModel::for_year(2027.5).map(|model| model.epoch()), Some(2025.0));
+        assert_eq!(MagneticModel::for_year(2030.5), None);
+        assert_eq!(MagneticModel::for_year(2019.0), None);
+    }
+
+    #[test]
+    fn test_from_cof() {
+        let mut text = String::from("    2020.0            WMM-2020        12/10/2019\n");
+        for (n, m, g, h, g_dot, h_dot) in WMM2020 {
+            text.push_str(&format!("{n:3}{m:3}{g:10.1}{h:10.1}{g_dot:11.1}{h_dot:11.1}\n"));
+        }
+        text.push_str("999999999999999999999999999999999999999999999999\n");
+        assert_eq!(MagneticModel::from_cof(&text), Ok(MagneticModel::wmm2020()));
+
+        let error = MagneticModel::from_cof("2020.0 WMM-2020\n  1  0  -29404.5  0.0\n").unwrap_err();
+        assert!(error.to_string().contains("line 2"), "{error}");
+        assert!(MagneticModel::from_cof("").is_err());
+        assert!(MagneticModel::from_cof("2020.0 WMM-2020\n").is_err());
+    }
+
+    #[test]
+    fn test_decimal_year() {
+        assert_eq!(decimal_year(2022, 1, 1), Ok(2022.0));
+        assert_eq!(decimal_year(2024, 7, 2), Ok(2024.5));
+        assert_eq!(decimal_year(2024, 12, 31), Ok(2024.0 + 365.0 / 366.0));
+        assert!(decimal_year(2023, 2, 29).is_err());
+        assert!(decimal_year(2023, 13, 1).is_err());
+        assert!(decimal_year(2023, 0, 1).is_err());
+    }
+}
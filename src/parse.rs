@@ -0,0 +1,850 @@
+use crate::dimension::Dimension;
+use crate::dynamic::DynQuantity;
+use crate::error::UnitError;
+use crate::units::*;
+
+/// UnitExpression struct
+///
+/// A unit written as products, quotients, and integer powers of known units,
+/// reduced to its Dimension and its scale relative to the SI unit of that
+/// Dimension.
+///
+/// Example: "kg*m/s^2" and "N" both parse to a scale of 1 with `Dimension::FORCE`.
+///
+/// Offset units (°C, °F) contribute the size of one degree only,
+/// as in "J/(kg·°C)".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitExpression {
+    /// size of one of this unit in the SI unit of the dimension
+    scale: f64,
+    /// dimension of the unit
+    dim: Dimension,
+}
+
+impl UnitExpression {
+    /// Create a new UnitExpression from its scale and dimension.
+    pub fn new(scale: f64, dim: Dimension) -> UnitExpression {
+        UnitExpression { scale, dim }
+    }
+
+    /// Create a UnitExpression for a single unit.
+    pub fn from_unit(unit: &UnitEnum) -> UnitExpression {
+        UnitExpression::new(unit.scale(), unit.dimension())
+    }
+
+    /// Get the size of one of this unit in the SI unit of the dimension.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Get the dimension of the unit.
+    pub fn dimension(&self) -> Dimension {
+        self.dim
+    }
+
+    /// Check whether values can be converted between the two units,
+    /// i.e. whether they have the same dimension.
+    pub fn is_convertible_to(&self, other: &UnitExpression) -> bool {
+        self.dim == other.dim
+    }
+
+    /// Get the factor that converts a value in this unit to the other unit.
+    ///
+    /// Returns an error if the units have different dimensions.
+    pub fn try_factor_to(&self, other: &UnitExpression) -> Result<f64, UnitError> {
+        if self.is_convertible_to(other) {
+            Ok(self.scale / other.scale)
+        } else {
+            Err(UnitError::DimensionMismatch { expected: self.dim, found: other.dim })
+        }
+    }
+
+    /// Convert a value in this unit to the other unit.
+    ///
+    /// Returns an error if the units have different dimensions.
+    pub fn try_convert(&self, value: f64, other: &UnitExpression) -> Result<f64, UnitError> {
+        Ok(value * self.try_factor_to(other)?)
+    }
+
+    /// Find the unit enum variant equal to this expression, if there is one.
+    /// Only units without an offset match, since an expression has no zero point.
+    /// Example: "kg*m/s^2" -> `ForceUnit::Newtons`
+    pub fn matching_unit(&self) -> Option<UnitEnum> {
+        UnitEnum::all()
+            .iter()
+            .find(|unit| {
+                unit.dimension() == self.dim
+                    && unit.si_conversion().offset == 0.0
+                    && (unit.scale() - self.scale).abs() <= 1e-9 * self.scale.abs()
+            })
+            .copied()
+    }
+
+    /// Multiply by another unit.
+    ///
+    /// Returns an error if an exponent of the dimension overflows.
+    pub fn try_mul(&self, other: &UnitExpression) -> Result<UnitExpression, UnitError> {
+        Ok(UnitExpression::new(self.scale * other.scale, self.dim.try_mul(other.dim)?))
+    }
+
+    /// Divide by another unit.
+    ///
+    /// Returns an error if an exponent of the dimension overflows.
+    pub fn try_div(&self, other: &UnitExpression) -> Result<UnitExpression, UnitError> {
+        Ok(UnitExpression::new(self.scale / other.scale, self.dim.try_div(other.dim)?))
+    }
+
+    /// Raise the unit to an integer power.
+    /// Example: "m".try_powi(2) is "m²"
+    ///
+    /// Returns an error if an exponent of the dimension overflows.
+    pub fn try_powi(&self, power: i8) -> Result<UnitExpression, UnitError> {
+        Ok(UnitExpression::new(self.scale.powi(power.into()), self.dim.try_powi(power)?))
+    }
+
+    /// Raise the unit to an integer power.
+    /// Panicking wrapper around `try_powi`.
+    pub fn powi(&self, power: i8) -> UnitExpression {
+        self.try_powi(power)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Multiplying two units
+/// Panics if an exponent overflows, see `UnitExpression::try_mul`.
+impl std::ops::Mul for UnitExpression {
+    type Output = UnitExpression;
+
+    fn mul(self, rhs: UnitExpression) -> UnitExpression {
+        self.try_mul(&rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Dividing two units
+/// Panics if an exponent overflows, see `UnitExpression::try_div`.
+impl std::ops::Div for UnitExpression {
+    type Output = UnitExpression;
+
+    fn div(self, rhs: UnitExpression) -> UnitExpression {
+        self.try_div(&rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Implementing FromStr trait for UnitExpression
+/// Same as `parse_unit_expression`.
+impl std::str::FromStr for UnitExpression {
+    type Err = UnitError;
+
+    fn from_str(input: &str) -> Result<UnitExpression, UnitError> {
+        parse_unit_expression(input)
+    }
+}
+
+// ---------------------------------------------------------
+
+/// SI prefixes and their factors.
+/// "da" comes before "d" so that the longer prefix is tried first.
+const SI_PREFIXES: &[(&str, f64)] = &[
+    ("da", 1e1),
+    ("Y", 1e24),
+    ("Z", 1e21),
+    ("E", 1e18),
+    ("P", 1e15),
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("µ", 1e-6),
+    ("μ", 1e-6),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+    ("f", 1e-15),
+    ("a", 1e-18),
+    ("z", 1e-21),
+    ("y", 1e-24),
+];
+
+/// Symbols that accept an SI prefix.
+const PREFIXABLE: &[&str] = &["m", "g", "s", "K", "A", "mol", "cd", "rad", "N", "Pa", "J", "W", "Hz", "bar"];
+
+/// Symbols that are not the abbreviation of a unit enum variant,
+/// with their scale and dimension exponents.
+const EXTRA_SYMBOLS: &[(&str, f64, [i8; 8])] = &[
+    ("h", 3600.0, [0, 0, 1, 0, 0, 0, 0, 0]),
+    ("A", 1.0, [0, 0, 0, 0, 1, 0, 0, 0]),
+    ("mol", 1.0, [0, 0, 0, 0, 0, 1, 0, 0]),
+    ("cd", 1.0, [0, 0, 0, 0, 0, 0, 1, 0]),
+    ("J", 1.0, [2, 1, -2, 0, 0, 0, 0, 0]),
+    ("W", 1.0, [2, 1, -3, 0, 0, 0, 0, 0]),
+    ("Hz", 1.0, [0, 0, -1, 0, 0, 0, 0, 0]),
+];
+
+/// Look up a single unit symbol, with or without an SI prefix.
+/// Symbols shared by several units resolve to the first in `UnitEnum::all`,
+/// so "g" is Grams, as SI prefixes expect.
+fn lookup_symbol(symbol: &str) -> Option<UnitExpression> {
+    if let Some(unit) = UnitEnum::all().iter().find(|unit| unit.abbr() == symbol) {
+        return Some(UnitExpression::from_unit(unit));
+    }
+    if let Some((_, scale, exponents)) = EXTRA_SYMBOLS.iter().find(|(name, _, _)| *name == symbol) {
+        return Some(UnitExpression::new(*scale, Dimension::from_exponents(*exponents)));
+    }
+    SI_PREFIXES.iter().find_map(|(prefix, factor)| {
+        let base = symbol.strip_prefix(prefix)?;
+        if !PREFIXABLE.contains(&base) {
+            return None;
+        }
+        let base = lookup_symbol(base)?;
+        Some(UnitExpression::new(base.scale * factor, base.dim))
+    })
+}
+
+// ---------------------------------------------------------
+
+/// Token of a unit expression
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Symbol(String),
+    One,
+    Times,
+    Per,
+    Power(i8),
+    Open,
+    Close,
+}
+
+/// Map a Unicode superscript character to its ASCII equivalent.
+fn from_superscript(c: char) -> Option<char> {
+    match c {
+        '⁻' => Some('-'),
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴' => Some('4'),
+        '⁵' => Some('5'),
+        '⁶' => Some('6'),
+        '⁷' => Some('7'),
+        '⁸' => Some('8'),
+        '⁹' => Some('9'),
+        _ => None,
+    }
+}
+
+/// Check whether a character can be part of a unit symbol.
+fn is_symbol_char(c: char) -> bool {
+    c.is_alphabetic() || c == '°'
+}
+
+/// Split a unit expression into tokens.
+fn tokenize(input: &str) -> Result<Vec<Token>, UnitError> {
+    let error = |reason: &str| UnitError::InvalidSyntax {
+        input: String::from(input),
+        reason: String::from(reason),
+    };
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '*' | '·' | '⋅' | '×' | '.' => tokens.push(Token::Times),
+            '/' => tokens.push(Token::Per),
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '^' => {
+                let mut exponent = String::new();
+                if let Some(sign) = chars.next_if(|c| *c == '-' || *c == '+') {
+                    exponent.push(sign);
+                }
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    exponent.push(digit);
+                }
+                let power = exponent.parse().map_err(|_| error("expected an integer after '^'"))?;
+                tokens.push(Token::Power(power));
+            }
+            c if from_superscript(c).is_some() => {
+                let mut exponent = String::from(from_superscript(c).unwrap_or('-'));
+                while let Some(c) = chars.next_if(|c| from_superscript(*c).is_some()) {
+                    exponent.push(from_superscript(c).unwrap_or('-'));
+                }
+                let power = exponent.parse().map_err(|_| error("invalid superscript exponent"))?;
+                tokens.push(Token::Power(power));
+            }
+            '1' if chars.peek().is_none_or(|c| !c.is_ascii_digit()) => tokens.push(Token::One),
+            c if is_symbol_char(c) => {
+                let mut symbol = String::from(c);
+                while let Some(c) = chars.next_if(|c| is_symbol_char(*c)) {
+                    symbol.push(c);
+                }
+                tokens.push(Token::Symbol(symbol));
+            }
+            _ => return Err(error(&format!("unexpected character '{c}'"))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser over the tokens of a unit expression.
+///
+/// expression := term { ("*" | "/" | whitespace) term }
+/// term       := factor [ power ]
+/// factor     := symbol | "1" | "(" expression ")"
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    /// Build an InvalidSyntax error for the input.
+    fn error(&self, reason: &str) -> UnitError {
+        UnitError::InvalidSyntax {
+            input: String::from(self.input),
+            reason: String::from(reason),
+        }
+    }
+
+    /// Turn an overflowing exponent into an InvalidSyntax error for the input.
+    fn check(&self, unit: Result<UnitExpression, UnitError>) -> Result<UnitExpression, UnitError> {
+        unit.map_err(|error| match error {
+            UnitError::OutOfDomain { value, unit } => self.error(&format!("the {unit} {value} is out of range")),
+            error => error,
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn expression(&mut self) -> Result<UnitExpression, UnitError> {
+        let mut unit = self.term()?;
+        loop {
+            match self.peek() {
+                Some(Token::Times) => {
+                    self.position += 1;
+                    let rhs = self.term()?;
+                    unit = self.check(unit.try_mul(&rhs))?;
+                }
+                Some(Token::Per) => {
+                    self.position += 1;
+                    let rhs = self.term()?;
+                    unit = self.check(unit.try_div(&rhs))?;
+                }
+                // two factors next to each other are multiplied, e.g. "N m"
+                Some(Token::Symbol(_)) | Some(Token::One) | Some(Token::Open) => {
+                    let rhs = self.term()?;
+                    unit = self.check(unit.try_mul(&rhs))?;
+                }
+                _ => return Ok(unit),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<UnitExpression, UnitError> {
+        let unit = self.factor()?;
+        match self.peek() {
+            Some(Token::Power(power)) => {
+                let power = *power;
+                self.position += 1;
+                self.check(unit.try_powi(power))
+            }
+            _ => Ok(unit),
+        }
+    }
+
+    fn factor(&mut self) -> Result<UnitExpression, UnitError> {
+        let token = self.peek().cloned();
+        self.position += 1;
+        match token {
+            Some(Token::Symbol(symbol)) => lookup_symbol(&symbol).ok_or(UnitError::UnknownUnit(symbol)),
+            Some(Token::One) => Ok(UnitExpression::new(1.0, Dimension::DIMENSIONLESS)),
+            Some(Token::Open) => {
+                let unit = self.expression()?;
+                match self.peek() {
+                    Some(Token::Close) => {
+                        self.position += 1;
+                        Ok(unit)
+                    }
+                    _ => Err(self.error("missing ')'")),
+                }
+            }
+            Some(_) => Err(self.error("expected a unit")),
+            None => Err(self.error("unexpected end of expression")),
+        }
+    }
+}
+
+/// Parse a compound unit expression.
+///
+/// Supports products ("*", "·", "×", or a space), quotients ("/"),
+/// integer powers ("^2", "^-1", "²", "⁻¹"), parentheses, and SI prefixes
+/// ("kN", "µs"). Symbols are the abbreviations of the unit enums, plus the
+/// SI units A, mol, cd, J, W, Hz, and "h" for hours.
+/// Operators are evaluated left to right, so "W/m·K" is "(W/m)·K".
+///
+/// # Example
+/// ```rust
+/// use units_and_values::parse::*;
+///
+/// let newton = parse_unit_expression("N").unwrap();
+/// let derived = parse_unit_expression("kg*m/s^2").unwrap();
+/// assert!(newton.is_convertible_to(&derived));
+/// assert_eq!(derived.try_factor_to(&newton), Ok(1.0));
+/// ```
+pub fn parse_unit_expression(input: &str) -> Result<UnitExpression, UnitError> {
+    let mut parser = Parser {
+        input,
+        tokens: tokenize(input)?,
+        position: 0,
+    };
+    let unit = parser.expression()?;
+    match parser.peek() {
+        None => Ok(unit),
+        Some(Token::Close) => Err(parser.error("unmatched ')'")),
+        Some(_) => Err(parser.error("expected an operator")),
+    }
+}
+
+// ---------------------------------------------------------
+
+/// ParsedQuantity struct
+///
+/// A quantity parsed from text, such as "12.5 km", keeping the unit it was
+/// written in alongside the converted DynQuantity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParsedQuantity {
+    /// number as written in the text
+    value: f64,
+    /// unit as written in the text
+    unit: UnitEnum,
+    /// value converted to the SI unit of the dimension
+    quantity: DynQuantity,
+}
+
+impl ParsedQuantity {
+    /// Get the number as written, in the parsed unit.
+    /// Example: 12.5 for "12.5 km"
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the unit the quantity was written in.
+    /// Example: `UnitEnum::Length(LengthUnit::Kilometers)` for "12.5 km"
+    pub fn unit(&self) -> UnitEnum {
+        self.unit
+    }
+
+    /// Get the quantity converted to the SI unit of its dimension.
+    /// Example: 12500 m for "12.5 km"
+    pub fn quantity(&self) -> DynQuantity {
+        self.quantity
+    }
+}
+
+/// Implementing FromStr trait for ParsedQuantity
+/// Same as `parse_quantity`.
+impl std::str::FromStr for ParsedQuantity {
+    type Err = UnitError;
+
+    fn from_str(input: &str) -> Result<ParsedQuantity, UnitError> {
+        parse_quantity(input)
+    }
+}
+
+/// Split the leading number off a quantity string.
+/// Accepts a sign ("+", "-", or "−"), a decimal mantissa, and an exponent ("1.5e-3"),
+/// or a whole number and a fraction ("3 5/8", "5/8").
+/// Returns the number and the rest of the string.
+fn split_number(input: &str) -> Option<(f64, &str)> {
+    let (value, rest) = split_decimal(input)?;
+    if value.fract() != 0.0 {
+        return Some((value, rest));
+    }
+    // "5/8"
+    if let Some((denominator, tail)) = rest.strip_prefix('/').and_then(split_denominator) {
+        return Some((value / denominator, tail));
+    }
+    // "3 5/8"
+    let spaced = rest.trim_start();
+    if spaced.len() < rest.len() {
+        if let Some((numerator, after)) = split_whole(spaced) {
+            if let Some((denominator, tail)) = after.strip_prefix('/').and_then(split_denominator) {
+                let fraction = numerator / denominator;
+                return Some((if value.is_sign_negative() { value - fraction } else { value + fraction }, tail));
+            }
+        }
+    }
+    Some((value, rest))
+}
+
+/// Split a whole number of at least one digit off the start of a string.
+fn split_whole(input: &str) -> Option<(f64, &str)> {
+    let len = input.bytes().take_while(u8::is_ascii_digit).count();
+    let number = input[..len].parse().ok()?;
+    Some((number, &input[len..]))
+}
+
+/// Split the denominator of a fraction, a whole number above zero, off the start of a string.
+fn split_denominator(input: &str) -> Option<(f64, &str)> {
+    split_whole(input).filter(|(denominator, _)| *denominator > 0.0)
+}
+
+/// Split a leading decimal number, with an optional sign and exponent, off a string.
+fn split_decimal(input: &str) -> Option<(f64, &str)> {
+    let mut number = String::new();
+    let mut chars = input.char_indices().peekable();
+    if let Some((_, sign)) = chars.next_if(|(_, c)| matches!(c, '+' | '-' | '−')) {
+        number.push(if sign == '+' { '+' } else { '-' });
+    }
+    let mut digits = 0;
+    while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit() || *c == '.') {
+        digits += usize::from(c.is_ascii_digit());
+        number.push(c);
+    }
+    if digits == 0 {
+        return None;
+    }
+    // only take the exponent if digits follow, so that a unit starting with "e" is left alone
+    let rest = &input[chars.peek().map_or(input.len(), |(i, _)| *i)..];
+    let exponent_len = exponent_length(rest);
+    number.push_str(&rest[..exponent_len]);
+    let value = number.parse().ok()?;
+    Some((value, &rest[exponent_len..]))
+}
+
+/// Get the length of a leading exponent such as "e-3", or 0 if there is none.
+fn exponent_length(input: &str) -> usize {
+    let bytes = input.as_bytes();
+    if !matches!(bytes.first(), Some(b'e') | Some(b'E')) {
+        return 0;
+    }
+    let sign = usize::from(matches!(bytes.get(1), Some(b'+') | Some(b'-')));
+    let digits = bytes[1 + sign..].iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 { 0 } else { 1 + sign + digits }
+}
+
+/// Find the unit written as `symbol`, of any dimension or only of `dimension`.
+/// Tries abbreviations and names first, then falls back to a unit expression
+/// that equals one of the units, so "kg*m/s^2" finds Newtons.
+fn find_unit(symbol: &str, dimension: Option<Dimension>) -> Result<UnitEnum, UnitError> {
+    let named = match dimension {
+        Some(dimension) => UnitEnum::parse_as(dimension, symbol),
+        None => symbol.parse::<UnitEnum>(),
+    };
+    match named {
+        Err(UnitError::UnknownUnit(_)) => {
+            let unit = match parse_unit_expression(symbol) {
+                // a malformed expression, as in "m^100*m^100", says more than an unknown unit
+                Err(error @ UnitError::InvalidSyntax { .. }) => return Err(error),
+                expression => expression.ok().and_then(|expression| expression.matching_unit()),
+            }
+            .ok_or_else(|| UnitError::UnknownUnit(String::from(symbol)))?;
+            match dimension {
+                Some(expected) if unit.dimension() != expected => {
+                    Err(UnitError::DimensionMismatch { expected, found: unit.dimension() })
+                }
+                _ => Ok(unit),
+            }
+        }
+        result => result,
+    }
+}
+
+/// Parse a quantity written as a number followed by a unit.
+///
+/// The number may have a sign and an exponent ("-1.5e3"), and may be
+/// separated from the unit by any amount of whitespace, including
+/// non-breaking spaces. The unit may be any abbreviation or name of any
+/// dimension, including the Unicode symbols "°", "µ", and "²".
+///
+/// Returns an error if the number or unit is missing, unknown, or ambiguous
+/// (see `parse_quantity_as`), or if the value is out of the unit's domain.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::parse::*;
+/// use units_and_values::units::*;
+///
+/// let pressure = parse_quantity("29.92 inHg").unwrap();
+/// assert_eq!(pressure.value(), 29.92);
+/// assert_eq!(pressure.unit(), UnitEnum::Pressure(PressureUnit::InchesOfMercury));
+///
+/// let temperature = parse_quantity("-40 °F").unwrap();
+/// assert_eq!(temperature.unit(), UnitEnum::Temperature(TemperatureUnit::Fehrenheit));
+/// assert!((temperature.quantity().si_value() - 233.15).abs() < 1e-9);
+/// ```
+pub fn parse_quantity(input: &str) -> Result<ParsedQuantity, UnitError> {
+    parse_quantity_in(input, None)
+}
+
+/// Parse a quantity of the given dimension.
+/// Same as `parse_quantity`, but only considers units of `dimension`,
+/// so symbols shared between dimensions, such as "g", resolve deterministically.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::dimension::Dimension;
+/// use units_and_values::parse::*;
+/// use units_and_values::units::*;
+///
+/// assert!(parse_quantity("3 g").is_err());
+/// let acceleration = parse_quantity_as(Dimension::ACCELERATION, "3 g").unwrap();
+/// assert_eq!(acceleration.unit(), UnitEnum::Acceleration(AccelerationUnit::StandardGravity));
+/// ```
+pub fn parse_quantity_as(dimension: Dimension, input: &str) -> Result<ParsedQuantity, UnitError> {
+    parse_quantity_in(input, Some(dimension))
+}
+
+/// Parse a quantity, optionally only among the units of one dimension.
+fn parse_quantity_in(input: &str, dimension: Option<Dimension>) -> Result<ParsedQuantity, UnitError> {
+    let syntax_error = |reason: &str| UnitError::InvalidSyntax {
+        input: String::from(input),
+        reason: String::from(reason),
+    };
+    let (value, rest) = split_number(input.trim()).ok_or_else(|| syntax_error("expected a number"))?;
+    let symbol = rest.trim();
+    if symbol.is_empty() {
+        return Err(syntax_error("expected a unit after the number"));
+    }
+    let unit = match find_unit(symbol, dimension) {
+        Ok(unit) => unit,
+        // maybe several parts, as in "5 ft 11 in"; if not, the first error says more
+        Err(error) => return parse_compound_in(input, dimension).map_err(|_| error),
+    };
+    Ok(ParsedQuantity {
+        value,
+        unit,
+        quantity: DynQuantity::try_from_unit(value, &unit)?,
+    })
+}
+
+/// Shorthand symbols for the parts of a compound quantity, as in `5'11"` and "1h23m",
+/// tried when the symbol names no unit of the dimension of the parts before it.
+const COMPOUND_SYMBOLS: &[(&str, UnitEnum)] = &[
+    ("'", UnitEnum::Length(LengthUnit::Feet)),
+    ("′", UnitEnum::Length(LengthUnit::Feet)),
+    ("\"", UnitEnum::Length(LengthUnit::Inches)),
+    ("″", UnitEnum::Length(LengthUnit::Inches)),
+    ("m", UnitEnum::Time(TimeUnit::Minutes)),
+    ("d", UnitEnum::Time(TimeUnit::Days)),
+    ("w", UnitEnum::Time(TimeUnit::Weeks)),
+];
+
+/// Parse a quantity written as several numbers and units, each unit strictly
+/// smaller than the one before it, such as "5 ft 11 in", `5'11"`, "1h23m" or "3 lb 4 oz", optionally only
+/// among the units of one dimension. A sign before the first number applies
+/// to the whole quantity.
+///
+/// The parsed value and unit are the total in the last unit written,
+/// so "1 hr 30 min" is 90 Minutes.
+fn parse_compound_in(input: &str, dimension: Option<Dimension>) -> Result<ParsedQuantity, UnitError> {
+    let syntax_error = |reason: &str| UnitError::InvalidSyntax {
+        input: String::from(input),
+        reason: String::from(reason),
+    };
+    let (first, mut rest) = split_number(input.trim()).ok_or_else(|| syntax_error("expected a number"))?;
+    let sign = if first.is_sign_negative() { -1.0 } else { 1.0 };
+    let mut number = first.abs();
+    let mut dimension = dimension;
+    let mut total = 0.0;
+    let mut last: Option<UnitEnum> = None;
+    loop {
+        let end = rest.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(rest.len());
+        let symbol = rest[..end].trim();
+        if symbol.is_empty() {
+            return Err(syntax_error("expected a unit after each number"));
+        }
+        let unit = find_unit(symbol, dimension).or_else(|error| {
+            COMPOUND_SYMBOLS
+                .iter()
+                .find(|(shorthand, unit)| *shorthand == symbol && dimension.is_none_or(|d| unit.dimension() == d))
+                .map(|(_, unit)| *unit)
+                .ok_or(error)
+        })?;
+        if unit.si_conversion().offset != 0.0 {
+            return Err(syntax_error("units with an offset cannot be combined"));
+        }
+        if last.is_some_and(|previous| unit.scale() >= previous.scale()) {
+            return Err(syntax_error("each unit must be smaller than the one before it"));
+        }
+        dimension = Some(unit.dimension());
+        total += unit.try_to_si(number)?;
+        last = Some(unit);
+        rest = &rest[end..];
+        if rest.trim().is_empty() {
+            break;
+        }
+        // only the first number has a sign
+        (number, rest) = split_number(rest).ok_or_else(|| syntax_error("expected a number after each unit"))?;
+    }
+    let unit = last.ok_or_else(|| syntax_error("expected a unit after the number"))?;
+    let quantity = DynQuantity::new(sign * total, unit.dimension());
+    Ok(ParsedQuantity {
+        value: unit.try_from_si(quantity.si_value())?,
+        unit,
+        quantity,
+    })
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> UnitExpression {
+        parse_unit_expression(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_compound_units() {
+        assert_eq!(parse("kg*m/s^2"), UnitExpression::new(1.0, Dimension::FORCE));
+        assert_eq!(parse("kg·m·s⁻²"), parse("(kg m)/s²"));
+        assert_eq!(parse("m/s²").dimension(), Dimension::ACCELERATION);
+        assert_eq!(parse("1/s").dimension(), Dimension::TIME.powi(-1));
+
+        let torque = parse("ft·lbf");
+        assert_eq!(torque.dimension(), parse("N*m").dimension());
+        assert!((torque.scale() - 0.3048 * 4.4482216152605).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_parse_prefixes() {
+        assert_eq!(parse("kN").scale(), 1000.0);
+        assert_eq!(parse("µs"), parse("us"));
+        assert_eq!(parse("mrad").dimension(), Dimension::ANGLE);
+        assert!((parse("dam").scale() - 10.0).abs() < 1e-12);
+        assert_eq!(parse("min").scale(), 60.0);
+        assert_eq!(parse("kPa").scale(), 1000.0);
+    }
+
+    #[test]
+    fn test_convertible_units() {
+        let newton = parse("N");
+        let derived = parse("kg*m/s^2");
+        assert!(newton.is_convertible_to(&derived));
+        assert_eq!(derived.matching_unit(), Some(UnitEnum::Force(ForceUnit::Newtons)));
+        assert_eq!(parse("km/h").try_convert(36.0, &parse("m/s")), Ok(10.0));
+        assert!(parse("N").try_factor_to(&parse("Pa")).is_err());
+    }
+
+    #[test]
+    fn test_parse_compound_quantities() {
+        let height = parse_quantity("5'11\"").unwrap();
+        assert_eq!(height.unit(), UnitEnum::Length(LengthUnit::Inches));
+        assert_eq!(height.value(), 71.0);
+        assert_eq!(parse_quantity("5 ft 11 in").unwrap().quantity(), height.quantity());
+        assert_eq!(parse_quantity("6′").unwrap().unit(), UnitEnum::Length(LengthUnit::Feet));
+
+        let time = parse_quantity("1h23m").unwrap();
+        assert_eq!(time.value(), 83.0);
+        assert_eq!(time.unit(), UnitEnum::Time(TimeUnit::Minutes));
+        assert_eq!(parse_quantity("1 day 2 hr 3 min 4 s").unwrap().quantity().si_value(), 93784.0);
+        assert_eq!(parse_quantity("-1 h 30 min").unwrap().quantity().si_value(), -5400.0);
+
+        let mass = parse_quantity_as(Dimension::MASS, "3 lb 4 oz").unwrap();
+        assert!((mass.quantity().si_value() - 3.25 * 0.45359237).abs() < 1e-12);
+
+        let board = parse_quantity("3 5/8 in").unwrap();
+        assert_eq!((board.value(), board.unit()), (3.625, UnitEnum::Length(LengthUnit::Inches)));
+        assert_eq!(parse_quantity("-1 1/2 hr").unwrap().value(), -1.5);
+        assert_eq!(parse_quantity("5/8 in").unwrap().value(), 0.625);
+        assert_eq!(parse_quantity("5 ft 3 5/8 in").unwrap().value(), 63.625);
+        assert!(parse_quantity("1/0 in").is_err());
+        assert!(parse_quantity("5 ft 11").is_err());
+        assert!(parse_quantity("5 ft 11 kg").is_err());
+        assert!(parse_quantity("11 in 5 ft").is_err());
+        assert!(parse_quantity("5 ft 5 ft").is_err());
+        assert!(parse_quantity("5 ft 12 in 3 in").is_err());
+        assert!(parse_quantity("1 mi 2 yd 3 ft 4 in").is_ok());
+        assert!(matches!(
+            parse_compound_in("1 min 2 hr", None),
+            Err(UnitError::InvalidSyntax { reason, .. }) if reason.contains("smaller")
+        ));
+        assert!(parse_quantity("1 °C 5 K").is_err());
+        assert!(parse_quantity_as(Dimension::LENGTH, "1h23m").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(parse_unit_expression("kg*/s"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_unit_expression("(m/s"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_unit_expression("m)"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_unit_expression("m^"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_unit_expression(""), Err(UnitError::InvalidSyntax { .. })));
+        assert_eq!(
+            parse_unit_expression("furlong/fortnight"),
+            Err(UnitError::UnknownUnit(String::from("furlong")))
+        );
+        // exponents that overflow the dimension
+        assert_eq!(
+            parse_unit_expression("(m^100)^2"),
+            Err(UnitError::InvalidSyntax {
+                input: String::from("(m^100)^2"),
+                reason: String::from("the m exponent 200 is out of range"),
+            })
+        );
+        assert!(matches!(parse_unit_expression("m^100*m^100"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_unit_expression("s^-100/s^100"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_unit_expression("m^1000"), Err(UnitError::InvalidSyntax { .. })));
+    }
+
+    #[test]
+    fn test_parse_quantity() {
+        let length = parse_quantity("12.5 km").unwrap();
+        assert_eq!(length.value(), 12.5);
+        assert_eq!(length.unit(), UnitEnum::Length(LengthUnit::Kilometers));
+        assert_eq!(length.quantity().si_value(), 12500.0);
+
+        assert_eq!(parse_quantity("12.5km"), parse_quantity("  12.5 \u{a0} km "));
+        assert_eq!(parse_quantity("-40°F"), parse_quantity("-40 ° F"));
+        assert_eq!(parse_quantity("−40 °F"), parse_quantity("-40 °F"));
+        assert_eq!(parse_quantity("1.5e3 m").unwrap().value(), 1500.0);
+        assert_eq!(parse_quantity("+2E-3 s").unwrap().value(), 0.002);
+        assert_eq!(parse_quantity(".5 Nmi").unwrap().value(), 0.5);
+        assert_eq!(parse_quantity("5 μs").unwrap().unit(), UnitEnum::Time(TimeUnit::Microseconds));
+        assert_eq!(
+            parse_quantity("9.81 m/s^2").unwrap().unit(),
+            UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared)
+        );
+        assert_eq!(parse_quantity("3 Kilometers").unwrap().unit(), UnitEnum::Length(LengthUnit::Kilometers));
+        assert_eq!(parse_quantity("-40 degF"), parse_quantity("-40 °F"));
+        assert_eq!(parse_quantity("100 kg*m/s^2").unwrap().unit(), UnitEnum::Force(ForceUnit::Newtons));
+    }
+
+    #[test]
+    fn test_parse_quantity_errors() {
+        assert!(matches!(parse_quantity("km"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_quantity("12.5"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_quantity("1e999 m"), Err(UnitError::NonFinite(_))));
+        assert!(matches!(parse_quantity("-500 °F"), Err(UnitError::OutOfDomain { .. })));
+        assert_eq!(parse_quantity("3 parsecs"), Err(UnitError::UnknownUnit(String::from("parsecs"))));
+        assert!(matches!(parse_quantity("5 mil"), Err(UnitError::AmbiguousUnit { .. })));
+        assert!(matches!(parse_quantity("5 m^100*m^100"), Err(UnitError::InvalidSyntax { .. })));
+        assert!(matches!(parse_quantity("5 (m^100)^2"), Err(UnitError::InvalidSyntax { .. })));
+    }
+
+    #[test]
+    fn test_parse_quantity_as() {
+        let length = parse_quantity_as(Dimension::LENGTH, "5 mil").unwrap();
+        assert_eq!(length.unit(), UnitEnum::Length(LengthUnit::Mils));
+        assert!(matches!(parse_quantity_as(Dimension::ANGLE, "5 mil"), Err(UnitError::AmbiguousUnit { .. })));
+        let bearing = parse_quantity_as(Dimension::ANGLE, "3200 NATO mil").unwrap();
+        assert_eq!(bearing.unit(), UnitEnum::Bearing(BearingUnit::NatoMils));
+        assert!((bearing.quantity().si_value() - std::f64::consts::PI).abs() < 1e-15);
+        assert_eq!(
+            parse_quantity_as(Dimension::FORCE, "100 kg*m/s^2").unwrap().unit(),
+            UnitEnum::Force(ForceUnit::Newtons)
+        );
+        assert_eq!(
+            parse_quantity_as(Dimension::PRESSURE, "100 kg*m/s^2"),
+            Err(UnitError::DimensionMismatch { expected: Dimension::PRESSURE, found: Dimension::FORCE })
+        );
+    }
+}
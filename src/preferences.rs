@@ -0,0 +1,376 @@
+use crate::dimension::Dimension;
+use crate::error::UnitError;
+use crate::system::{UnitSystem, UnitValue};
+use crate::units::*;
+use crate::values::Value;
+
+/// UnitPreferences struct
+///
+/// The units a user chose to see values in: a base `UnitSystem`, and the
+/// dimensions where the user picked another unit. Dimensions without an
+/// override use the preferred unit of the base system.
+///
+/// Preferences save to and load from a small TOML-like text format, with
+/// the base system given by name and each override by the name of its
+/// `UnitEnum` dimension and a unit string:
+///
+/// ```text
+/// # Unit preferences
+/// base = "Aviation"
+///
+/// [units]
+/// Length = "m"
+/// Pressure = "hPa"
+/// ```
+///
+/// The base system is saved by its name, so only the presets load back as
+/// they are. A base that is not a preset, such as one from `UnitSystem::new`
+/// or a preset changed with `with_preferred`, is saved as the preset of the
+/// same name (or the metric system) and the preferred units where it differs
+/// from that preset, so every preferred unit loads back but the base's
+/// alternative units do not.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::preferences::*;
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let preferences = UnitPreferences::parse("base = \"Aviation\"\n[units]\nPressure = \"hPa\"").unwrap();
+/// let setting = AbsolutePressure::new(29.92, &PressureUnit::InchesOfMercury);
+/// assert_eq!(format!("{:.0}", preferences.to_preferred(&setting)), "1013 hPa");
+/// let altitude = Elevation::new(3048.0, &LengthUnit::Meters);
+/// assert_eq!(format!("{:.0}", preferences.to_preferred(&altitude)), "10000 ft");
+///
+/// let saved = preferences.to_string();
+/// assert_eq!(UnitPreferences::parse(&saved), Ok(preferences));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitPreferences {
+    /// system the preferences start from
+    base: UnitSystem,
+    /// units chosen instead of the base system's, at most one per dimension
+    overrides: Vec<UnitEnum>,
+}
+
+/// Implementing Default trait for UnitPreferences
+/// The metric system without overrides.
+impl Default for UnitPreferences {
+    fn default() -> Self {
+        UnitPreferences::new(UnitSystem::default())
+    }
+}
+
+impl UnitPreferences {
+    /// Create new UnitPreferences on top of the given system, without overrides.
+    pub fn new(base: UnitSystem) -> UnitPreferences {
+        UnitPreferences {
+            base,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Prefer `unit` for its dimension, replacing any unit chosen before.
+    pub fn with_unit(mut self, unit: UnitEnum) -> UnitPreferences {
+        self.set_unit(unit);
+        self
+    }
+
+    /// Prefer `unit` for its dimension, replacing any unit chosen before.
+    pub fn set_unit(&mut self, unit: UnitEnum) {
+        self.clear_unit(unit.dimension());
+        self.overrides.push(unit);
+        // in the order of the UnitEnum variants, so equal preferences compare equal
+        let order = dimension_units();
+        self.overrides
+            .sort_by_key(|unit| order.iter().position(|other| other.dimension() == unit.dimension()));
+    }
+
+    /// Go back to the base system's unit for the dimension.
+    pub fn clear_unit(&mut self, dimension: Dimension) {
+        self.overrides.retain(|unit| unit.dimension() != dimension);
+    }
+
+    /// Get the system the preferences start from.
+    pub fn base(&self) -> &UnitSystem {
+        &self.base
+    }
+
+    /// Get the units chosen instead of the base system's.
+    pub fn overrides(&self) -> &[UnitEnum] {
+        &self.overrides
+    }
+
+    /// Get the preferred unit for the dimension: the override if there is one,
+    /// or else the preferred unit of the base system.
+    /// Returns None if no unit measures the dimension.
+    pub fn unit_for(&self, dimension: Dimension) -> Option<UnitEnum> {
+        self.overrides
+            .iter()
+            .copied()
+            .find(|unit| unit.dimension() == dimension)
+            .or_else(|| self.base.preferred(dimension))
+    }
+
+    /// Get the preferred unit of every dimension of `UnitEnum`, in the order of its variants.
+    pub fn units(&self) -> Vec<UnitEnum> {
+        dimension_units()
+            .iter()
+            .filter_map(|unit| self.unit_for(unit.dimension()))
+            .collect()
+    }
+
+    /// Get the base system with the overrides as the preferred units,
+    /// for `Value::to_system` and `Humanize::system`.
+    pub fn system(&self) -> UnitSystem {
+        self.overrides
+            .iter()
+            .fold(self.base.clone(), |system, unit| system.with_preferred(*unit))
+    }
+
+    /// Get a value in the preferred unit for its dimension.
+    ///
+    /// Returns an error if the value cannot be converted to that unit.
+    pub fn try_to_preferred<V: Value>(&self, value: &V) -> Result<UnitValue, UnitError> {
+        value.try_to_system(&self.system())
+    }
+
+    /// Get a value in the preferred unit for its dimension.
+    /// Panicking wrapper around `try_to_preferred`.
+    pub fn to_preferred<V: Value>(&self, value: &V) -> UnitValue {
+        self.try_to_preferred(value)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Parse preferences from their text format, described on `UnitPreferences`.
+    ///
+    /// Keys are matched in any case. Values may be quoted with `"` or `'`,
+    /// and `#` starts a comment. Without a `base` line the base is the metric system.
+    ///
+    /// Returns an error naming the line and the text on it if a line does not
+    /// parse, names an unknown base system, dimension, or unit, or sets a
+    /// dimension twice.
+    pub fn parse(text: &str) -> Result<UnitPreferences, UnitError> {
+        let lines: Vec<&str> = text.lines().collect();
+        let error = |line: usize, reason: &str| UnitError::InvalidSyntax {
+            input: String::from(lines[line - 1].trim()),
+            reason: format!("line {line}: {reason}"),
+        };
+        let mut preferences = UnitPreferences::default();
+        let mut base_line = None;
+        let mut in_units = false;
+        for (index, line) in lines.iter().enumerate() {
+            let number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[') {
+                let section = section.split('#').next().unwrap_or_default().trim_end();
+                match section.strip_suffix(']').map(str::trim) {
+                    Some(name) if name.eq_ignore_ascii_case("units") => in_units = true,
+                    Some(name) => return Err(error(number, &format!("unknown section \"{name}\", expected [units]"))),
+                    None => return Err(error(number, "expected ']' after the section name")),
+                }
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| error(number, "expected key = \"value\""))?;
+            let key = key.trim();
+            let value = parse_value(value).ok_or_else(|| error(number, "expected a quoted value"))?;
+
+            if !in_units {
+                if !key.eq_ignore_ascii_case("base") {
+                    return Err(error(number, &format!("unknown key \"{key}\", expected base or a [units] section")));
+                }
+                if base_line.is_some() {
+                    return Err(error(number, "base is set twice"));
+                }
+                preferences.base = UnitSystem::preset(value)
+                    .ok_or_else(|| error(number, &format!("unknown unit system \"{value}\"")))?;
+                base_line = Some(number);
+                continue;
+            }
+            let dimension = dimension_units()
+                .into_iter()
+                .find(|unit| unit.dimension_name().eq_ignore_ascii_case(key))
+                .ok_or_else(|| error(number, &format!("unknown dimension \"{key}\"")))?
+                .dimension();
+            let unit = UnitEnum::parse_as(dimension, value).map_err(|unit_error| error(number, &unit_error.to_string()))?;
+            if preferences.overrides.iter().any(|other| other.dimension() == dimension) {
+                return Err(error(number, &format!("{key} is set twice")));
+            }
+            preferences.set_unit(unit);
+        }
+        Ok(preferences)
+    }
+}
+
+/// Implementing FromStr trait for UnitPreferences
+/// Same as `UnitPreferences::parse`.
+impl std::str::FromStr for UnitPreferences {
+    type Err = UnitError;
+
+    fn from_str(input: &str) -> Result<UnitPreferences, UnitError> {
+        UnitPreferences::parse(input)
+    }
+}
+
+/// Implementing Display trait for UnitPreferences
+/// Writes the text format that `UnitPreferences::parse` reads,
+/// with the overrides in the order of the `UnitEnum` variants.
+/// A base that is not a preset is written as described on `UnitPreferences`.
+impl std::fmt::Display for UnitPreferences {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let saved_base = UnitSystem::preset(self.base.name()).unwrap_or_default();
+        writeln!(f, "# Unit preferences")?;
+        writeln!(f, "base = \"{}\"", saved_base.name())?;
+        writeln!(f)?;
+        writeln!(f, "[units]")?;
+        for dimension_unit in dimension_units() {
+            let dimension = dimension_unit.dimension();
+            let unit = self
+                .overrides
+                .iter()
+                .copied()
+                .find(|unit| unit.dimension() == dimension)
+                // where the base differs from the preset that is saved in its place
+                .or_else(|| self.base.preferred(dimension).filter(|unit| saved_base.preferred(dimension) != Some(*unit)));
+            if let Some(unit) = unit {
+                writeln!(f, "{} = \"{}\"", unit.dimension_name(), unit.abbr())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Get the first unit of each dimension of `UnitEnum`, in the order of its variants.
+fn dimension_units() -> Vec<UnitEnum> {
+    let mut units: Vec<UnitEnum> = Vec::new();
+    for unit in UnitEnum::all() {
+        if !units.iter().any(|other| other.dimension() == unit.dimension()) {
+            units.push(*unit);
+        }
+    }
+    units
+}
+
+/// Parse the value of a `key = value` line: a quoted string, or bare text,
+/// followed by an optional comment.
+/// Returns None for an unclosed quote, text after the closing quote, or an empty value.
+fn parse_value(text: &str) -> Option<&str> {
+    let text = text.trim();
+    let value = match text.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let (value, rest) = text[1..].split_once(quote)?;
+            let rest = rest.trim();
+            if !rest.is_empty() && !rest.starts_with('#') {
+                return None;
+            }
+            value
+        }
+        _ => text.split('#').next().unwrap_or_default().trim(),
+    };
+    if value.is_empty() { None } else { Some(value) }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::*;
+
+    #[test]
+    fn test_overrides() {
+        let mut preferences = UnitPreferences::new(UnitSystem::aviation()).with_unit(UnitEnum::Pressure(PressureUnit::Hectopascals));
+        assert_eq!(preferences.unit_for(Dimension::PRESSURE), Some(UnitEnum::Pressure(PressureUnit::Hectopascals)));
+        assert_eq!(preferences.unit_for(Dimension::VELOCITY), Some(UnitEnum::Velocity(VelocityUnit::Knots)));
+        assert_eq!(preferences.units().len(), 9);
+
+        preferences.set_unit(UnitEnum::Pressure(PressureUnit::Milibars));
+        assert_eq!(preferences.overrides(), &[UnitEnum::Pressure(PressureUnit::Milibars)]);
+        let reordered = UnitPreferences::new(UnitSystem::aviation())
+            .with_unit(UnitEnum::Length(LengthUnit::Meters))
+            .with_unit(UnitEnum::Pressure(PressureUnit::Milibars));
+        assert_eq!(preferences.clone().with_unit(UnitEnum::Length(LengthUnit::Meters)), reordered);
+        let setting = AbsolutePressure::new(1013.0, &PressureUnit::Hectopascals);
+        assert_eq!(preferences.to_preferred(&setting).to_string(), "1013 mbar");
+
+        preferences.clear_unit(Dimension::PRESSURE);
+        assert_eq!(preferences.unit_for(Dimension::PRESSURE), Some(UnitEnum::Pressure(PressureUnit::InchesOfMercury)));
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = "# chosen by the user\nbase = 'US Customary'  # the default\n\n[units]\nlength = \"m\"\nBearing = mil6400\nMass = \"g\" # grams, not gravity\n";
+        let preferences = UnitPreferences::parse(text).unwrap();
+        assert_eq!(preferences.base().name(), "US Customary");
+        assert_eq!(preferences.unit_for(Dimension::LENGTH), Some(UnitEnum::Length(LengthUnit::Meters)));
+        assert_eq!(preferences.unit_for(Dimension::ANGLE), Some(UnitEnum::Bearing(BearingUnit::NatoMils)));
+        assert_eq!(preferences.unit_for(Dimension::MASS), Some(UnitEnum::Mass(MassUnit::Grams)));
+        assert_eq!(preferences.unit_for(Dimension::FORCE), Some(UnitEnum::Force(ForceUnit::PoundsForce)));
+        assert_eq!(UnitPreferences::parse(""), Ok(UnitPreferences::default()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = UnitPreferences::parse("base = \"SI\"\n[units]\nLength = \"furlong\"").unwrap_err();
+        assert_eq!(
+            error,
+            UnitError::InvalidSyntax {
+                input: String::from("Length = \"furlong\""),
+                reason: String::from("line 3: Invalid unit: unknown unit \"furlong\""),
+            }
+        );
+        let error = UnitPreferences::parse("[units]\nBearing = \"mil\"").unwrap_err();
+        assert!(error.to_string().contains("line 2") && error.to_string().contains("\"mil\" is ambiguous"), "{error}");
+        let error = UnitPreferences::parse("[units]\nLength = \"kg\"").unwrap_err();
+        assert!(error.to_string().contains("expected a Length unit"), "{error}");
+
+        for text in [
+            "base = \"Klingon\"",
+            "base = \"SI\"\nbase = \"SI\"",
+            "Length = \"m\"",
+            "[colours]",
+            "[units\n",
+            "[units]\nLength = \"m\"\nlength = \"ft\"",
+            "[units]\nVolume = \"l\"",
+            "[units]\nLength",
+            "[units]\nLength = \"m",
+            "[units]\nLength = \"m\" ft",
+            "[units]\nLength = \"\"",
+        ] {
+            assert!(matches!(UnitPreferences::parse(text), Err(UnitError::InvalidSyntax { .. })), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_save_every_unit() {
+        for system in UnitSystem::presets() {
+            let preferences = UnitPreferences::new(system);
+            assert_eq!(UnitPreferences::parse(&preferences.to_string()), Ok(preferences));
+        }
+        for unit in UnitEnum::all() {
+            let preferences = UnitPreferences::new(UnitSystem::nautical()).with_unit(*unit);
+            assert_eq!(UnitPreferences::parse(&preferences.to_string()), Ok(preferences), "{unit}");
+        }
+    }
+
+    #[test]
+    fn test_save_custom_base() {
+        let feet = UnitEnum::Length(LengthUnit::Feet);
+        let meters = UnitEnum::Length(LengthUnit::Meters);
+        let custom = UnitSystem::new("Mine", &[feet, UnitEnum::Temperature(TemperatureUnit::Rankine)]);
+        let tweaked = UnitSystem::aviation().with_preferred(meters);
+        for base in [custom, tweaked] {
+            let preferences = UnitPreferences::new(base.clone()).with_unit(UnitEnum::Pressure(PressureUnit::Hectopascals));
+            let loaded = UnitPreferences::parse(&preferences.to_string()).unwrap();
+            assert_eq!(loaded.units(), preferences.units(), "{}", base.name());
+        }
+
+        let tweaked = UnitPreferences::new(UnitSystem::aviation().with_preferred(meters));
+        let loaded = UnitPreferences::parse(&tweaked.to_string()).unwrap();
+        assert_eq!(loaded.base(), &UnitSystem::aviation());
+        assert_eq!(loaded.unit_for(Dimension::LENGTH), Some(meters));
+        assert_eq!(loaded.overrides(), &[meters]);
+    }
+}
@@ -0,0 +1,426 @@
+use crate::dimension::Dimension;
+use crate::format::ValueFormat;
+use crate::units::*;
+
+/// Units of time, shared by every system.
+const TIME_UNITS: &[UnitEnum] = &[
+    UnitEnum::Time(TimeUnit::Seconds),
+    UnitEnum::Time(TimeUnit::Milliseconds),
+    UnitEnum::Time(TimeUnit::Microseconds),
+    UnitEnum::Time(TimeUnit::Nanoseconds),
+    UnitEnum::Time(TimeUnit::Minutes),
+    UnitEnum::Time(TimeUnit::Hours),
+    UnitEnum::Time(TimeUnit::Days),
+    UnitEnum::Time(TimeUnit::Weeks),
+];
+
+/// The SI units and their decimal multiples, in order of preference for each dimension.
+const SI_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::Meters),
+    UnitEnum::Length(LengthUnit::Kilometers),
+    UnitEnum::Length(LengthUnit::Centimeters),
+    UnitEnum::Length(LengthUnit::Millimeters),
+    UnitEnum::Mass(MassUnit::Kilograms),
+    UnitEnum::Mass(MassUnit::Grams),
+    UnitEnum::Mass(MassUnit::Milligrams),
+    UnitEnum::Temperature(TemperatureUnit::Kelvin),
+    UnitEnum::Velocity(VelocityUnit::MetersPerSecond),
+    UnitEnum::Force(ForceUnit::Newtons),
+    UnitEnum::Pressure(PressureUnit::Pascals),
+    UnitEnum::Pressure(PressureUnit::Kilopascals),
+    UnitEnum::Pressure(PressureUnit::Megapascals),
+    UnitEnum::Bearing(BearingUnit::Radians),
+    UnitEnum::Bearing(BearingUnit::Milliradians),
+    UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared),
+    UnitEnum::Acceleration(AccelerationUnit::KilometersPerSecondSquared),
+];
+
+/// The metric units that read well, in order of preference for each dimension.
+const METRIC_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::Meters),
+    UnitEnum::Length(LengthUnit::Kilometers),
+    UnitEnum::Length(LengthUnit::Centimeters),
+    UnitEnum::Length(LengthUnit::Millimeters),
+    UnitEnum::Mass(MassUnit::Kilograms),
+    UnitEnum::Mass(MassUnit::Grams),
+    UnitEnum::Mass(MassUnit::Milligrams),
+    UnitEnum::Temperature(TemperatureUnit::Celcius),
+    UnitEnum::Temperature(TemperatureUnit::Kelvin),
+    UnitEnum::Velocity(VelocityUnit::MetersPerSecond),
+    UnitEnum::Velocity(VelocityUnit::KilometersPerHour),
+    UnitEnum::Force(ForceUnit::Newtons),
+    UnitEnum::Pressure(PressureUnit::Pascals),
+    UnitEnum::Pressure(PressureUnit::Kilopascals),
+    UnitEnum::Pressure(PressureUnit::Megapascals),
+    UnitEnum::Bearing(BearingUnit::Degrees),
+    UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared),
+];
+
+/// The US customary units that read well, in order of preference for each dimension.
+const US_CUSTOMARY_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::Feet),
+    UnitEnum::Length(LengthUnit::Inches),
+    UnitEnum::Length(LengthUnit::Miles),
+    UnitEnum::Mass(MassUnit::PoundsMass),
+    UnitEnum::Mass(MassUnit::Ounces),
+    UnitEnum::Temperature(TemperatureUnit::Fehrenheit),
+    UnitEnum::Temperature(TemperatureUnit::Rankine),
+    UnitEnum::Velocity(VelocityUnit::MilesPerHour),
+    UnitEnum::Velocity(VelocityUnit::FeetPerSecond),
+    UnitEnum::Force(ForceUnit::PoundsForce),
+    UnitEnum::Pressure(PressureUnit::PoundsPerSquareInch),
+    UnitEnum::Bearing(BearingUnit::Degrees),
+    UnitEnum::Acceleration(AccelerationUnit::FeetPerSecondSquared),
+];
+
+/// The imperial units, in order of preference for each dimension.
+/// The crate's feet and pounds are the international ones that both the
+/// imperial and US customary systems use; imperial adds yards.
+const IMPERIAL_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::Feet),
+    UnitEnum::Length(LengthUnit::Inches),
+    UnitEnum::Length(LengthUnit::Yards),
+    UnitEnum::Length(LengthUnit::Miles),
+    UnitEnum::Mass(MassUnit::PoundsMass),
+    UnitEnum::Mass(MassUnit::Ounces),
+    UnitEnum::Temperature(TemperatureUnit::Fehrenheit),
+    UnitEnum::Velocity(VelocityUnit::MilesPerHour),
+    UnitEnum::Force(ForceUnit::PoundsForce),
+    UnitEnum::Pressure(PressureUnit::PoundsPerSquareInch),
+    UnitEnum::Pressure(PressureUnit::InchesOfMercury),
+    UnitEnum::Bearing(BearingUnit::Degrees),
+    UnitEnum::Acceleration(AccelerationUnit::FeetPerSecondSquared),
+];
+
+/// The units of aviation: altitudes in feet, distances in nautical miles,
+/// speeds in knots, altimeter settings in inches of mercury, temperatures in degrees Celcius.
+const AVIATION_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::Feet),
+    UnitEnum::Length(LengthUnit::NauticalMiles),
+    UnitEnum::Mass(MassUnit::PoundsMass),
+    UnitEnum::Temperature(TemperatureUnit::Celcius),
+    UnitEnum::Velocity(VelocityUnit::Knots),
+    UnitEnum::Force(ForceUnit::PoundsForce),
+    UnitEnum::Pressure(PressureUnit::InchesOfMercury),
+    UnitEnum::Bearing(BearingUnit::Degrees),
+    UnitEnum::Acceleration(AccelerationUnit::StandardGravity),
+];
+
+/// The units of marine navigation: distances in nautical miles, speeds in knots,
+/// depths in meters, pressures in hectopascals.
+const NAUTICAL_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::NauticalMiles),
+    UnitEnum::Length(LengthUnit::Meters),
+    UnitEnum::Mass(MassUnit::Kilograms),
+    UnitEnum::Temperature(TemperatureUnit::Celcius),
+    UnitEnum::Velocity(VelocityUnit::Knots),
+    UnitEnum::Force(ForceUnit::Newtons),
+    UnitEnum::Pressure(PressureUnit::Hectopascals),
+    UnitEnum::Bearing(BearingUnit::Degrees),
+    UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared),
+];
+
+/// The units of weather reports: temperatures in degrees Celcius, pressures in
+/// hectopascals, winds in meters per second, precipitation in millimeters.
+const METEOROLOGICAL_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::Meters),
+    UnitEnum::Length(LengthUnit::Kilometers),
+    UnitEnum::Length(LengthUnit::Millimeters),
+    UnitEnum::Mass(MassUnit::Kilograms),
+    UnitEnum::Mass(MassUnit::Grams),
+    UnitEnum::Temperature(TemperatureUnit::Celcius),
+    UnitEnum::Velocity(VelocityUnit::MetersPerSecond),
+    UnitEnum::Velocity(VelocityUnit::KilometersPerHour),
+    UnitEnum::Force(ForceUnit::Newtons),
+    UnitEnum::Pressure(PressureUnit::Hectopascals),
+    UnitEnum::Bearing(BearingUnit::Degrees),
+    UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared),
+];
+
+/// UnitSystem struct
+///
+/// A named set of units to show values in, such as the SI units or the units
+/// of aviation. For each dimension, the first unit listed is the preferred
+/// one, which `Value::to_system` converts to, and the others are the
+/// alternatives that `humanize` picks for larger or smaller values.
+/// Dimensions without a unit in the system fall back to their SI unit.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::dimension::Dimension;
+/// use units_and_values::system::*;
+/// use units_and_values::units::*;
+///
+/// let system = UnitSystem::us_customary();
+/// assert_eq!(system.preferred(Dimension::LENGTH), Some(UnitEnum::Length(LengthUnit::Feet)));
+/// assert_eq!(system.preferred(Dimension::FORCE), Some(UnitEnum::Force(ForceUnit::PoundsForce)));
+///
+/// let glider = UnitSystem::aviation().with_preferred(UnitEnum::Length(LengthUnit::Meters));
+/// assert_eq!(glider.preferred(Dimension::LENGTH), Some(UnitEnum::Length(LengthUnit::Meters)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitSystem {
+    /// name of the system, as in "Metric"
+    name: String,
+    /// units of the system, in order of preference for each dimension
+    units: Vec<UnitEnum>,
+}
+
+impl UnitSystem {
+    /// Create a new UnitSystem from its units, in order of preference for each dimension.
+    pub fn new(name: &str, units: &[UnitEnum]) -> UnitSystem {
+        UnitSystem {
+            name: String::from(name),
+            units: units.to_vec(),
+        }
+    }
+
+    /// The SI system: meters, kilograms, Kelvin, radians, and their decimal multiples.
+    pub fn si() -> UnitSystem {
+        UnitSystem::new("SI", &[SI_UNITS, TIME_UNITS].concat())
+    }
+
+    /// The metric system: meters, kilograms, degrees Celcius, and their multiples.
+    pub fn metric() -> UnitSystem {
+        UnitSystem::new("Metric", &[METRIC_UNITS, TIME_UNITS].concat())
+    }
+
+    /// The US customary system: feet, pounds, degrees Fehrenheit, and their multiples.
+    pub fn us_customary() -> UnitSystem {
+        UnitSystem::new("US Customary", &[US_CUSTOMARY_UNITS, TIME_UNITS].concat())
+    }
+
+    /// The imperial system: feet, yards, pounds, degrees Fehrenheit, and their multiples.
+    pub fn imperial() -> UnitSystem {
+        UnitSystem::new("Imperial", &[IMPERIAL_UNITS, TIME_UNITS].concat())
+    }
+
+    /// The units of aviation: feet, nautical miles, knots, inches of mercury and degrees Celcius.
+    pub fn aviation() -> UnitSystem {
+        UnitSystem::new("Aviation", &[AVIATION_UNITS, TIME_UNITS].concat())
+    }
+
+    /// The units of marine navigation: nautical miles, knots, meters and hectopascals.
+    pub fn nautical() -> UnitSystem {
+        UnitSystem::new("Nautical", &[NAUTICAL_UNITS, TIME_UNITS].concat())
+    }
+
+    /// The units of weather reports: degrees Celcius, hectopascals, meters per second and millimeters.
+    pub fn meteorological() -> UnitSystem {
+        UnitSystem::new("Meteorological", &[METEOROLOGICAL_UNITS, TIME_UNITS].concat())
+    }
+
+    /// Get every built in system.
+    pub fn presets() -> Vec<UnitSystem> {
+        vec![
+            UnitSystem::si(),
+            UnitSystem::metric(),
+            UnitSystem::us_customary(),
+            UnitSystem::imperial(),
+            UnitSystem::aviation(),
+            UnitSystem::nautical(),
+            UnitSystem::meteorological(),
+        ]
+    }
+
+    /// Get the built in system with the given name, in any case and with or
+    /// without spaces, as in "US Customary" or "us_customary".
+    pub fn preset(name: &str) -> Option<UnitSystem> {
+        let normalize = |name: &str| -> String {
+            name.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        UnitSystem::presets()
+            .into_iter()
+            .find(|system| normalize(system.name()) == normalize(name))
+    }
+
+    /// Make `unit` the preferred unit of its dimension,
+    /// keeping the other units of the dimension as alternatives.
+    pub fn with_preferred(mut self, unit: UnitEnum) -> UnitSystem {
+        self.units.retain(|other| *other != unit);
+        let position = self
+            .units
+            .iter()
+            .position(|other| other.dimension() == unit.dimension())
+            .unwrap_or(self.units.len());
+        self.units.insert(position, unit);
+        self
+    }
+
+    /// Get the name of the system.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get every unit of the system.
+    pub fn units(&self) -> &[UnitEnum] {
+        &self.units
+    }
+
+    /// Get the units of the system for the dimension, in order of preference,
+    /// or the SI unit of the dimension if the system has none.
+    pub fn units_of(&self, dimension: Dimension) -> Vec<UnitEnum> {
+        let units: Vec<UnitEnum> = self
+            .units
+            .iter()
+            .copied()
+            .filter(|unit| unit.dimension() == dimension)
+            .collect();
+        if units.is_empty() {
+            si_unit(dimension).into_iter().collect()
+        } else {
+            units
+        }
+    }
+
+    /// Get the preferred unit of the system for the dimension,
+    /// or the SI unit of the dimension if the system has none.
+    /// Returns None if no unit measures the dimension.
+    pub fn preferred(&self, dimension: Dimension) -> Option<UnitEnum> {
+        self.units_of(dimension).first().copied()
+    }
+}
+
+/// Implementing Default trait for UnitSystem
+/// The metric system.
+impl Default for UnitSystem {
+    fn default() -> Self {
+        UnitSystem::metric()
+    }
+}
+
+/// Get the SI unit of the dimension, if any unit measures it.
+fn si_unit(dimension: Dimension) -> Option<UnitEnum> {
+    UnitEnum::all()
+        .iter()
+        .copied()
+        .find(|unit| unit.dimension() == dimension && unit.si_conversion() == SiConversion::new(1.0, 0.0))
+}
+
+// ---------------------------------------------------------
+
+/// UnitValue struct
+///
+/// A number together with the unit it is in, as returned by `Value::to_system`.
+/// Displays like the values, as in "29.92 inHg".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitValue {
+    /// number in the unit
+    value: f64,
+    /// unit of the number
+    unit: UnitEnum,
+}
+
+impl UnitValue {
+    /// Create a new UnitValue from a number and its unit.
+    pub fn new(value: f64, unit: UnitEnum) -> UnitValue {
+        UnitValue { value, unit }
+    }
+
+    /// Get the number, in the unit.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the unit of the number.
+    pub fn unit(&self) -> UnitEnum {
+        self.unit
+    }
+}
+
+/// Implementing Display trait for UnitValue
+/// Writes the number and unit with the options of `ValueFormat::from_formatter`.
+impl std::fmt::Display for UnitValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&ValueFormat::from_formatter(f).format_in(self.value, &self.unit))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bearing::BearingValue;
+    use crate::values::*;
+
+    /// The dimensions of the UnitEnum variants
+    const DIMENSIONS: [Dimension; 9] = [
+        Dimension::LENGTH,
+        Dimension::MASS,
+        Dimension::TIME,
+        Dimension::TEMPERATURE,
+        Dimension::VELOCITY,
+        Dimension::FORCE,
+        Dimension::PRESSURE,
+        Dimension::ANGLE,
+        Dimension::ACCELERATION,
+    ];
+
+    #[test]
+    fn test_units_of() {
+        let metric = UnitSystem::metric();
+        assert_eq!(metric.name(), "Metric");
+        assert_eq!(metric.preferred(Dimension::TEMPERATURE), Some(UnitEnum::Temperature(TemperatureUnit::Celcius)));
+        assert_eq!(metric.units_of(Dimension::MASS).len(), 3);
+        assert_eq!(metric.units_of(Dimension::TIME).len(), 8);
+
+        let custom = UnitSystem::new("Pressure only", &[UnitEnum::Pressure(PressureUnit::Hectopascals)]);
+        assert_eq!(custom.units_of(Dimension::PRESSURE), vec![UnitEnum::Pressure(PressureUnit::Hectopascals)]);
+        assert_eq!(custom.preferred(Dimension::LENGTH), Some(UnitEnum::Length(LengthUnit::Meters)));
+        assert_eq!(custom.preferred(Dimension::AREA), None);
+    }
+
+    #[test]
+    fn test_presets_cover_every_dimension() {
+        for system in UnitSystem::presets() {
+            for dimension in DIMENSIONS {
+                assert!(
+                    system.units().iter().any(|unit| unit.dimension() == dimension),
+                    "{} has no {dimension} unit",
+                    system.name()
+                );
+            }
+            assert!(!system.units().iter().any(|unit| unit.si_conversion().gauge), "{}", system.name());
+        }
+    }
+
+    #[test]
+    fn test_preset_names() {
+        assert_eq!(UnitSystem::preset("us_customary"), Some(UnitSystem::us_customary()));
+        assert_eq!(UnitSystem::preset("AVIATION"), Some(UnitSystem::aviation()));
+        assert_eq!(UnitSystem::preset("Klingon"), None);
+        for system in UnitSystem::presets() {
+            assert_eq!(UnitSystem::preset(system.name()).as_ref(), Some(&system));
+        }
+    }
+
+    #[test]
+    fn test_with_preferred() {
+        let system = UnitSystem::nautical().with_preferred(UnitEnum::Length(LengthUnit::Meters));
+        assert_eq!(
+            system.units_of(Dimension::LENGTH),
+            vec![UnitEnum::Length(LengthUnit::Meters), UnitEnum::Length(LengthUnit::NauticalMiles)]
+        );
+        let system = system.with_preferred(UnitEnum::Length(LengthUnit::Feet));
+        assert_eq!(system.units_of(Dimension::LENGTH).len(), 3);
+        assert_eq!(system.units().len(), UnitSystem::nautical().units().len() + 1);
+    }
+
+    #[test]
+    fn test_to_system() {
+        let altitude = Elevation::new(3048.0, &LengthUnit::Meters);
+        let setting = AbsolutePressure::new(1013.25, &PressureUnit::Hectopascals);
+        let aviation = UnitSystem::aviation();
+        assert!((altitude.to_system(&aviation).value() - 10000.0).abs() < 1e-9);
+        assert_eq!(format!("{:.2}", setting.to_system(&aviation)), "29.92 inHg");
+        assert_eq!(format!("{:.1}", TemperatureValue::new(15.0, &TemperatureUnit::Celcius).to_system(&UnitSystem::si())), "288.1 K");
+        assert_eq!(TemperatureDelta::new(10.0, &TemperatureUnit::Celcius).to_system(&UnitSystem::us_customary()).value(), 18.0);
+        assert_eq!(
+            BearingValue::new(180.0, &BearingUnit::Degrees).to_system(&UnitSystem::si()),
+            UnitValue::new(std::f64::consts::PI, UnitEnum::Bearing(BearingUnit::Radians))
+        );
+    }
+}
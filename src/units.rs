@@ -1,5 +1,8 @@
+use crate::dimension::Dimension;
+use crate::error::UnitError;
+
 /// Enum containing all possible unit types
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UnitEnum {
     Length(LengthUnit),
     Mass(MassUnit),
@@ -12,21 +15,686 @@ pub enum UnitEnum {
     Acceleration(AccelerationUnit),
 }
 
+/// Every unit, grouped by dimension in the order of the UnitEnum variants.
+const ALL_UNITS: &[UnitEnum] = &[
+    UnitEnum::Length(LengthUnit::Meters),
+    UnitEnum::Length(LengthUnit::Kilometers),
+    UnitEnum::Length(LengthUnit::Centimeters),
+    UnitEnum::Length(LengthUnit::Millimeters),
+    UnitEnum::Length(LengthUnit::Inches),
+    UnitEnum::Length(LengthUnit::Feet),
+    UnitEnum::Length(LengthUnit::Yards),
+    UnitEnum::Length(LengthUnit::Miles),
+    UnitEnum::Length(LengthUnit::NauticalMiles),
+    UnitEnum::Length(LengthUnit::Mils),
+    UnitEnum::Mass(MassUnit::Kilograms),
+    UnitEnum::Mass(MassUnit::Grams),
+    UnitEnum::Mass(MassUnit::Milligrams),
+    UnitEnum::Mass(MassUnit::PoundsMass),
+    UnitEnum::Mass(MassUnit::Ounces),
+    UnitEnum::Mass(MassUnit::Slugs),
+    UnitEnum::Time(TimeUnit::Seconds),
+    UnitEnum::Time(TimeUnit::Milliseconds),
+    UnitEnum::Time(TimeUnit::Microseconds),
+    UnitEnum::Time(TimeUnit::Nanoseconds),
+    UnitEnum::Time(TimeUnit::Minutes),
+    UnitEnum::Time(TimeUnit::Hours),
+    UnitEnum::Time(TimeUnit::Days),
+    UnitEnum::Time(TimeUnit::Weeks),
+    UnitEnum::Temperature(TemperatureUnit::Kelvin),
+    UnitEnum::Temperature(TemperatureUnit::Celcius),
+    UnitEnum::Temperature(TemperatureUnit::Rankine),
+    UnitEnum::Temperature(TemperatureUnit::Fehrenheit),
+    UnitEnum::Velocity(VelocityUnit::Knots),
+    UnitEnum::Velocity(VelocityUnit::MilesPerHour),
+    UnitEnum::Velocity(VelocityUnit::KilometersPerHour),
+    UnitEnum::Velocity(VelocityUnit::FeetPerSecond),
+    UnitEnum::Velocity(VelocityUnit::MetersPerSecond),
+    UnitEnum::Force(ForceUnit::Newtons),
+    UnitEnum::Force(ForceUnit::PoundsForce),
+    UnitEnum::Force(ForceUnit::KilogramsForce),
+    UnitEnum::Pressure(PressureUnit::Pascals),
+    UnitEnum::Pressure(PressureUnit::Kilopascals),
+    UnitEnum::Pressure(PressureUnit::Megapascals),
+    UnitEnum::Pressure(PressureUnit::Hectopascals),
+    UnitEnum::Pressure(PressureUnit::InchesOfMercury),
+    UnitEnum::Pressure(PressureUnit::MillimetersOfMercury),
+    UnitEnum::Pressure(PressureUnit::Milibars),
+    UnitEnum::Pressure(PressureUnit::Atmospheres),
+    UnitEnum::Pressure(PressureUnit::PoundsPerSquareInch),
+    UnitEnum::Pressure(PressureUnit::Bars),
+    UnitEnum::Pressure(PressureUnit::PoundsPerSquareInchGauge),
+    UnitEnum::Pressure(PressureUnit::BarsGauge),
+    UnitEnum::Bearing(BearingUnit::Radians),
+    UnitEnum::Bearing(BearingUnit::Degrees),
+    UnitEnum::Bearing(BearingUnit::ArcMinutes),
+    UnitEnum::Bearing(BearingUnit::ArcSeconds),
+    UnitEnum::Bearing(BearingUnit::Gradians),
+    UnitEnum::Bearing(BearingUnit::Milliradians),
+    UnitEnum::Bearing(BearingUnit::NatoMils),
+    UnitEnum::Bearing(BearingUnit::WarsawPactMils),
+    UnitEnum::Bearing(BearingUnit::SwedishMils),
+    UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared),
+    UnitEnum::Acceleration(AccelerationUnit::FeetPerSecondSquared),
+    UnitEnum::Acceleration(AccelerationUnit::KilometersPerSecondSquared),
+    UnitEnum::Acceleration(AccelerationUnit::MilesPerHourPerSecond),
+    UnitEnum::Acceleration(AccelerationUnit::StandardGravity),
+];
+
+impl UnitEnum {
+    /// Parse a unit of the given dimension.
+    /// Same as `str::parse`, but only considers units of `dimension`,
+    /// so symbols shared between dimensions resolve deterministically.
+    ///
+    /// Returns an error if the input names no unit of `dimension`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::dimension::Dimension;
+    /// use units_and_values::units::*;
+    ///
+    /// assert!("g".parse::<UnitEnum>().is_err());
+    /// assert_eq!(UnitEnum::parse_as(Dimension::ACCELERATION, "g"), Ok(UnitEnum::Acceleration(AccelerationUnit::StandardGravity)));
+    /// assert_eq!(UnitEnum::parse_as(Dimension::MASS, "g"), Ok(UnitEnum::Mass(MassUnit::Grams)));
+    /// ```
+    pub fn parse_as(dimension: Dimension, input: &str) -> Result<UnitEnum, UnitError> {
+        find_unit(input, Some(dimension))
+    }
+
+    /// Get every unit of every dimension.
+    pub fn all() -> &'static [UnitEnum] {
+        ALL_UNITS
+    }
+
+    /// Get the name of one of the unit.
+    /// Example: "Foot" for Feet
+    pub fn name_singular(&self) -> String {
+        singular_name(&self.to_string())
+    }
+
+    /// Get the name of the dimension the unit belongs to.
+    /// Example: "Length"
+    pub fn dimension_name(&self) -> &'static str {
+        match self {
+            Self::Length(_) => "Length",
+            Self::Mass(_) => "Mass",
+            Self::Time(_) => "Time",
+            Self::Temperature(_) => "Temperature",
+            Self::Velocity(_) => "Velocity",
+            Self::Force(_) => "Force",
+            Self::Pressure(_) => "Pressure",
+            Self::Bearing(_) => "Bearing",
+            Self::Acceleration(_) => "Acceleration",
+        }
+    }
+
+    /// Get the physical dimension of the unit.
+    /// Example: `Dimension::VELOCITY` for Knots
+    pub fn dimension(&self) -> Dimension {
+        match self {
+            Self::Length(_) => Dimension::LENGTH,
+            Self::Mass(_) => Dimension::MASS,
+            Self::Time(_) => Dimension::TIME,
+            Self::Temperature(_) => Dimension::TEMPERATURE,
+            Self::Velocity(_) => Dimension::VELOCITY,
+            Self::Force(_) => Dimension::FORCE,
+            Self::Pressure(_) => Dimension::PRESSURE,
+            Self::Bearing(_) => Dimension::ANGLE,
+            Self::Acceleration(_) => Dimension::ACCELERATION,
+        }
+    }
+
+    /// Convert a value in this unit to the default (SI) unit of its dimension.
+    /// Example: 1 Kilometer -> 1000 (Meters)
+    pub fn try_to_si(&self, value: f64) -> Result<f64, UnitError> {
+        match self {
+            Self::Length(unit) => unit.try_convert(value, self, &LengthUnit::default()),
+            Self::Mass(unit) => unit.try_convert(value, self, &MassUnit::default()),
+            Self::Time(unit) => unit.try_convert(value, self, &TimeUnit::default()),
+            Self::Temperature(unit) => unit.try_convert(value, self, &TemperatureUnit::default()),
+            Self::Velocity(unit) => unit.try_convert(value, self, &VelocityUnit::default()),
+            Self::Force(unit) => unit.try_convert(value, self, &ForceUnit::default()),
+            Self::Pressure(unit) => unit.try_convert(value, self, &PressureUnit::default()),
+            Self::Bearing(unit) => unit.try_convert(value, self, &BearingUnit::default()),
+            Self::Acceleration(unit) => unit.try_convert(value, self, &AccelerationUnit::default()),
+        }
+    }
+
+    /// Convert a value in the default (SI) unit of the dimension to this unit.
+    /// Example: 1000 (Meters) -> 1 Kilometer
+    pub fn try_from_si(&self, value: f64) -> Result<f64, UnitError> {
+        match self {
+            Self::Length(unit) => unit.try_convert(value, &LengthUnit::default(), self),
+            Self::Mass(unit) => unit.try_convert(value, &MassUnit::default(), self),
+            Self::Time(unit) => unit.try_convert(value, &TimeUnit::default(), self),
+            Self::Temperature(unit) => unit.try_convert(value, &TemperatureUnit::default(), self),
+            Self::Velocity(unit) => unit.try_convert(value, &VelocityUnit::default(), self),
+            Self::Force(unit) => unit.try_convert(value, &ForceUnit::default(), self),
+            Self::Pressure(unit) => unit.try_convert(value, &PressureUnit::default(), self),
+            Self::Bearing(unit) => unit.try_convert(value, &BearingUnit::default(), self),
+            Self::Acceleration(unit) => unit.try_convert(value, &AccelerationUnit::default(), self),
+        }
+    }
+
+    /// Convert a difference in the default (SI) unit of the dimension to this unit.
+    /// Only the scale applies, as in `try_convert_delta_to`.
+    /// Example: a change of 10 (Kelvin) -> a change of 10 °C
+    pub fn try_delta_from_si(&self, value: f64) -> Result<f64, UnitError> {
+        check_finite(value)?;
+        Ok(self.si_conversion().delta().from_si(value))
+    }
+
+    /// Convert a value in this unit to another unit of the same dimension.
+    /// Example: 1 Mile -> 5280 (Feet)
+    ///
+    /// Returns an error if the units have different dimensions,
+    /// or if the value is not finite or out of the unit's domain.
+    pub fn try_convert_to(&self, value: f64, to: &UnitEnum) -> Result<f64, UnitError> {
+        match self {
+            Self::Length(unit) => unit.try_convert(value, self, to),
+            Self::Mass(unit) => unit.try_convert(value, self, to),
+            Self::Time(unit) => unit.try_convert(value, self, to),
+            Self::Temperature(unit) => unit.try_convert(value, self, to),
+            Self::Velocity(unit) => unit.try_convert(value, self, to),
+            Self::Force(unit) => unit.try_convert(value, self, to),
+            Self::Pressure(unit) => unit.try_convert(value, self, to),
+            Self::Bearing(unit) => unit.try_convert(value, self, to),
+            Self::Acceleration(unit) => unit.try_convert(value, self, to),
+        }
+    }
+
+    /// Convert a difference between two values in this unit to another unit of the same dimension.
+    /// Only the scale applies, not the offset, so a change of 10 °C is a change of 10 K.
+    /// For units without an offset this is the same as `try_convert_to`.
+    ///
+    /// Returns an error if the units have different dimensions,
+    /// or if the value is not finite.
+    pub fn try_convert_delta_to(&self, value: f64, to: &UnitEnum) -> Result<f64, UnitError> {
+        check_finite(value)?;
+        let from = unit_conversion(self.dimension(), self)?;
+        let to = unit_conversion(self.dimension(), to)?;
+        Ok(from.delta().convert(value, &to.delta()))
+    }
+
+    /// Convert a value in this unit to the SI unit of its dimension,
+    /// measuring a gauge unit from `reference` (in the SI unit) instead of its standard reference.
+    /// Example: 10 psig over a 90000 Pa atmosphere -> 158947.57 (Pascals)
+    ///
+    /// Units that are not gauge units ignore the reference, as in `try_to_si`.
+    pub fn try_to_si_with_reference(&self, value: f64, reference: f64) -> Result<f64, UnitError> {
+        let conversion = self.si_conversion();
+        if !conversion.gauge {
+            return self.try_to_si(value);
+        }
+        check_finite(value)?;
+        check_finite(reference)?;
+        Ok(conversion.with_reference(reference).to_si(value))
+    }
+
+    /// Convert a value in the SI unit of the dimension to this unit,
+    /// measuring a gauge unit from `reference` (in the SI unit) instead of its standard reference.
+    ///
+    /// Units that are not gauge units ignore the reference, as in `try_from_si`.
+    pub fn try_from_si_with_reference(&self, value: f64, reference: f64) -> Result<f64, UnitError> {
+        let conversion = self.si_conversion();
+        if !conversion.gauge {
+            return self.try_from_si(value);
+        }
+        check_finite(value)?;
+        check_finite(reference)?;
+        Ok(conversion.with_reference(reference).from_si(value))
+    }
+
+    /// Get how the wrapped unit relates to the SI unit of its dimension.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Length(unit) => unit.si_conversion(),
+            Self::Mass(unit) => unit.si_conversion(),
+            Self::Time(unit) => unit.si_conversion(),
+            Self::Temperature(unit) => unit.si_conversion(),
+            Self::Velocity(unit) => unit.si_conversion(),
+            Self::Force(unit) => unit.si_conversion(),
+            Self::Pressure(unit) => unit.si_conversion(),
+            Self::Bearing(unit) => unit.si_conversion(),
+            Self::Acceleration(unit) => unit.si_conversion(),
+        }
+    }
+
+    /// Get the size of one unit in the default (SI) unit of its dimension.
+    /// For Temperature this is the size of one degree, ignoring the offset.
+    /// Example: 0.3048 for Feet, 5/9 for Fehrenheit
+    pub const fn scale(&self) -> f64 {
+        self.si_conversion().scale
+    }
+
+    /// Get the abbreviation of the wrapped unit.
+    /// Example: "°F"
+    pub fn abbr(&self) -> String {
+        match self {
+            Self::Length(unit) => unit.abbr(),
+            Self::Mass(unit) => unit.abbr(),
+            Self::Time(unit) => unit.abbr(),
+            Self::Temperature(unit) => unit.abbr(),
+            Self::Velocity(unit) => unit.abbr(),
+            Self::Force(unit) => unit.abbr(),
+            Self::Pressure(unit) => unit.abbr(),
+            Self::Bearing(unit) => unit.abbr(),
+            Self::Acceleration(unit) => unit.abbr(),
+        }
+    }
+}
+
+/// Implementing Display trait for UnitEnum
+/// Display the name of the wrapped unit
+/// Example: "Meters"
+impl std::fmt::Display for UnitEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Length(unit) => write!(f, "{unit}"),
+            Self::Mass(unit) => write!(f, "{unit}"),
+            Self::Time(unit) => write!(f, "{unit}"),
+            Self::Temperature(unit) => write!(f, "{unit}"),
+            Self::Velocity(unit) => write!(f, "{unit}"),
+            Self::Force(unit) => write!(f, "{unit}"),
+            Self::Pressure(unit) => write!(f, "{unit}"),
+            Self::Bearing(unit) => write!(f, "{unit}"),
+            Self::Acceleration(unit) => write!(f, "{unit}"),
+        }
+    }
+}
+
+/// Implementing FromStr trait for UnitEnum
+/// Searches the units of every dimension, so the parsed variant tells which
+/// dimension matched.
+///
+/// Accepts, in order of preference:
+/// - the abbreviation, as in "km" or "°F"
+/// - the name, in any case and singular or plural, as in "meter", "Metres", or "nautical mile"
+/// - the name with its abbreviation, as in "Meters (m)"
+/// - ASCII spellings, as in "deg", "degF", "us", or "m/s^2"
+/// - the abbreviation in any case, as in "KPA"
+///
+/// Returns `UnitError::AmbiguousUnit` if the input names units of several
+/// dimensions, such as "g". Use `UnitEnum::parse_as` to pick one.
+/// A bare "mil" is ambiguous even among the angle units, since each
+/// convention of mils has its own abbreviation, such as "mil6400" for NATO mils.
+///
+/// # Example
+/// ```rust
+/// use units_and_values::units::*;
+///
+/// let unit: UnitEnum = "kilometre".parse().unwrap();
+/// assert_eq!(unit, UnitEnum::Length(LengthUnit::Kilometers));
+/// assert_eq!(unit.dimension_name(), "Length");
+/// assert_eq!("Fahrenheit".parse(), Ok(UnitEnum::Temperature(TemperatureUnit::Fehrenheit)));
+/// ```
+impl std::str::FromStr for UnitEnum {
+    type Err = UnitError;
+
+    fn from_str(input: &str) -> Result<UnitEnum, UnitError> {
+        find_unit(input, None)
+    }
+}
+
+/// Find the unit written as `input`, optionally only among the units of one dimension.
+/// See `FromStr for UnitEnum` for the accepted spellings.
+fn find_unit(input: &str, dimension: Option<Dimension>) -> Result<UnitEnum, UnitError> {
+    let trimmed = input.trim();
+    if let Some((name, _)) = trimmed.strip_suffix(')').and_then(|rest| rest.split_once(" (")) {
+        if let Ok(unit) = find_unit(name, dimension) {
+            return Ok(unit);
+        }
+    }
+    let symbol = normalize_symbol(trimmed);
+    let name = normalize_name(trimmed);
+    let units_where = |matches: &dyn Fn(&UnitEnum) -> bool| -> Vec<UnitEnum> {
+        ALL_UNITS.iter().copied().filter(|unit| matches(unit)).collect()
+    };
+    let stages = [
+        SHARED_SYMBOLS
+            .iter()
+            .filter(|(shared, _)| *shared == symbol || normalize_name(shared) == name)
+            .flat_map(|(_, units)| units.iter().copied())
+            .collect(),
+        units_where(&|unit| unit.abbr() == symbol),
+        units_where(&|unit| normalize_name(&unit.to_string()) == name),
+        UNIT_ALIASES
+            .iter()
+            .filter(|(alias, _)| *alias == symbol || normalize_name(alias) == name)
+            .map(|(_, unit)| *unit)
+            .collect(),
+        units_where(&|unit| unit.abbr().to_lowercase() == symbol.to_lowercase()),
+    ];
+
+    // the first stage with a match in the requested dimension wins
+    let mut other_dimension = None;
+    for candidates in stages {
+        let (mut wanted, others): (Vec<UnitEnum>, Vec<UnitEnum>) = candidates
+            .into_iter()
+            .partition(|unit| dimension.is_none_or(|dimension| unit.dimension() == dimension));
+        // several aliases of one unit, such as "lb" and "lbs", can match the same input
+        wanted.dedup();
+        match wanted[..] {
+            [] => other_dimension = other_dimension.or(others.first().copied()),
+            [unit] => return Ok(unit),
+            _ => {
+                return Err(UnitError::AmbiguousUnit {
+                    input: String::from(input),
+                    candidates: wanted,
+                })
+            }
+        }
+    }
+    match (dimension, other_dimension) {
+        (Some(expected), Some(unit)) => Err(UnitError::DimensionMismatch { expected, found: unit.dimension() }),
+        _ => Err(UnitError::UnknownUnit(String::from(input))),
+    }
+}
+
+/// Symbols that several units are written as, so they are always ambiguous
+/// unless a dimension is given and only one of the units has it.
+/// "mil" is the thou, and a mil of each of the angular conventions.
+const SHARED_SYMBOLS: &[(&str, &[UnitEnum])] = &[(
+    "mil",
+    &[
+        UnitEnum::Length(LengthUnit::Mils),
+        UnitEnum::Bearing(BearingUnit::Milliradians),
+        UnitEnum::Bearing(BearingUnit::NatoMils),
+        UnitEnum::Bearing(BearingUnit::WarsawPactMils),
+        UnitEnum::Bearing(BearingUnit::SwedishMils),
+    ],
+)];
+
+/// Alternative spellings of units: ASCII stand-ins for the Unicode abbreviations,
+/// common abbreviations other than ours, and correctly spelled names.
+const UNIT_ALIASES: &[(&str, UnitEnum)] = &[
+    ("NM", UnitEnum::Length(LengthUnit::NauticalMiles)),
+    ("thou", UnitEnum::Length(LengthUnit::Mils)),
+    ("lb", UnitEnum::Mass(MassUnit::PoundsMass)),
+    ("lbs", UnitEnum::Mass(MassUnit::PoundsMass)),
+    ("Pounds", UnitEnum::Mass(MassUnit::PoundsMass)),
+    ("slug", UnitEnum::Mass(MassUnit::Slugs)),
+    ("sec", UnitEnum::Time(TimeUnit::Seconds)),
+    ("us", UnitEnum::Time(TimeUnit::Microseconds)),
+    ("usec", UnitEnum::Time(TimeUnit::Microseconds)),
+    ("h", UnitEnum::Time(TimeUnit::Hours)),
+    ("hrs", UnitEnum::Time(TimeUnit::Hours)),
+    ("day", UnitEnum::Time(TimeUnit::Days)),
+    ("week", UnitEnum::Time(TimeUnit::Weeks)),
+    ("degF", UnitEnum::Temperature(TemperatureUnit::Fehrenheit)),
+    ("degC", UnitEnum::Temperature(TemperatureUnit::Celcius)),
+    ("degR", UnitEnum::Temperature(TemperatureUnit::Rankine)),
+    ("Fahrenheit", UnitEnum::Temperature(TemperatureUnit::Fehrenheit)),
+    ("Celsius", UnitEnum::Temperature(TemperatureUnit::Celcius)),
+    ("Degrees Fahrenheit", UnitEnum::Temperature(TemperatureUnit::Fehrenheit)),
+    ("Degrees Celsius", UnitEnum::Temperature(TemperatureUnit::Celcius)),
+    ("Degrees Rankine", UnitEnum::Temperature(TemperatureUnit::Rankine)),
+    ("kt", UnitEnum::Velocity(VelocityUnit::Knots)),
+    ("kn", UnitEnum::Velocity(VelocityUnit::Knots)),
+    ("kph", UnitEnum::Velocity(VelocityUnit::KilometersPerHour)),
+    ("fps", UnitEnum::Velocity(VelocityUnit::FeetPerSecond)),
+    ("Millibars", UnitEnum::Pressure(PressureUnit::Milibars)),
+    ("mb", UnitEnum::Pressure(PressureUnit::Milibars)),
+    ("psia", UnitEnum::Pressure(PressureUnit::PoundsPerSquareInch)),
+    ("bara", UnitEnum::Pressure(PressureUnit::Bars)),
+    ("deg", UnitEnum::Bearing(BearingUnit::Degrees)),
+    ("MOA", UnitEnum::Bearing(BearingUnit::ArcMinutes)),
+    ("NATO mil", UnitEnum::Bearing(BearingUnit::NatoMils)),
+    ("WP mil", UnitEnum::Bearing(BearingUnit::WarsawPactMils)),
+    ("streck", UnitEnum::Bearing(BearingUnit::SwedishMils)),
+    ("m/s^2", UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared)),
+    ("ft/s^2", UnitEnum::Acceleration(AccelerationUnit::FeetPerSecondSquared)),
+    ("km/s^2", UnitEnum::Acceleration(AccelerationUnit::KilometersPerSecondSquared)),
+];
+
+/// Normalise the spelling of a unit symbol.
+/// Replaces the Greek mu with the micro sign, "^2" with "²",
+/// and removes whitespace, so that "° F" matches "°F".
+pub(crate) fn normalize_symbol(symbol: &str) -> String {
+    symbol
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| if c == 'μ' { 'µ' } else { c })
+        .collect::<String>()
+        .replace("^2", "²")
+}
+
+/// Normalise the spelling of a unit name for comparison.
+/// Lowercases, spells "metre" as "meter", makes each word singular,
+/// and joins the words, so that "Nautical Miles" and "nautical-mile" match.
+fn normalize_name(name: &str) -> String {
+    name.to_lowercase()
+        .replace("metre", "meter")
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .map(singular)
+        .collect()
+}
+
+/// Make a unit name singular, keeping its case.
+/// The word that counts is the last one before "Per", "Of", "Force", "Mass"
+/// or "Gauge", or else the last word.
+/// Example: "Inches Of Mercury" -> "Inch Of Mercury", "Nautical Miles" -> "Nautical Mile"
+fn singular_name(name: &str) -> String {
+    let mut words: Vec<&str> = name.split(' ').collect();
+    let index = words
+        .iter()
+        .position(|word| matches!(*word, "Per" | "Of" | "Force" | "Mass" | "Gauge"))
+        .unwrap_or(words.len())
+        .saturating_sub(1);
+    if let Some(word) = words.get_mut(index) {
+        *word = if *word == "Feet" { "Foot" } else { singular(word) };
+    }
+    words.join(" ")
+}
+
+/// Make a lowercase English unit word singular.
+/// Example: "inches" -> "inch", "feet" -> "foot", "celsius" -> "celsius"
+fn singular(word: &str) -> &str {
+    if word == "feet" {
+        "foot"
+    } else if word.ends_with("ches") {
+        &word[..word.len() - 2]
+    } else if word.ends_with("ss") || word.ends_with("us") {
+        word
+    } else {
+        word.strip_suffix('s').unwrap_or(word)
+    }
+}
+
+/// Reject NaN and infinite inputs before they reach a conversion.
+fn check_finite(value: f64) -> Result<f64, UnitError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(UnitError::NonFinite(value))
+    }
+}
+
+// Exact definitions of the customary units, from NIST SP 811 Appendix B.
+// The other conversion factors are derived from these rather than rounded separately.
+
+/// International inch, in Meters
+const INCH: f64 = 0.0254;
+/// International foot, in Meters
+const FOOT: f64 = 0.3048;
+/// International yard (three feet, or 36 inches), in Meters
+/// Taken from the inch, since 36 inches round to 0.9144 exactly as written, where 3 feet do not.
+const YARD: f64 = 36.0 * INCH;
+/// Mil (a thousandth of an inch), in Meters
+const MIL: f64 = INCH * 0.001;
+/// International mile, in Meters
+const MILE: f64 = 1609.344;
+/// Nautical mile, in Meters
+const NAUTICAL_MILE: f64 = 1852.0;
+/// Avoirdupois pound, in Kilograms
+const POUND: f64 = 0.45359237;
+/// Standard acceleration of gravity, in Meters Per Second Squared
+const STANDARD_GRAVITY: f64 = 9.80665;
+/// Pound force (one pound under standard gravity), in Newtons
+const POUND_FORCE: f64 = POUND * STANDARD_GRAVITY;
+/// Conventional millimeter of mercury (13595.1 kg/m³ under standard gravity), in Pascals
+const MILLIMETER_OF_MERCURY: f64 = 133.322387415;
+/// Standard atmosphere, in Pascals
+/// This is also the reference of the gauge pressure units.
+const ATMOSPHERE: f64 = 101325.0;
+/// Bar, in Pascals
+const BAR: f64 = 100000.0;
+/// Minute, in Seconds
+const MINUTE: f64 = 60.0;
+/// Hour, in Seconds
+const HOUR: f64 = 3600.0;
+
+/// SiConversion struct
+///
+/// How a unit relates to the SI unit of its dimension:
+/// `si = (value + offset) * scale`.
+///
+/// Units whose zero is not the SI zero have an offset.
+/// Example: Fehrenheit has a scale of 5/9 and an offset of 459.67.
+///
+/// The zero of a gauge unit is a reference that can be moved, such as the
+/// atmospheric pressure that psig is measured from. Its offset is the
+/// standard reference, and `with_reference` replaces it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SiConversion {
+    /// size of one of the unit in the SI unit
+    pub scale: f64,
+    /// distance from the SI zero to the unit's zero, in the unit
+    pub offset: f64,
+    /// whether the unit's zero is a movable reference
+    pub gauge: bool,
+}
+
+impl SiConversion {
+    /// The SiConversion of an SI unit
+    pub const SI: SiConversion = SiConversion::linear(1.0);
+
+    /// Create a new SiConversion from its scale and offset.
+    pub const fn new(scale: f64, offset: f64) -> SiConversion {
+        SiConversion { scale, offset, gauge: false }
+    }
+
+    /// Create a new SiConversion for a gauge unit,
+    /// whose zero is at `reference` in the SI unit by default.
+    /// Example: psig, with the standard atmosphere as its reference
+    pub const fn new_gauge(scale: f64, reference: f64) -> SiConversion {
+        SiConversion { scale, offset: reference / scale, gauge: true }
+    }
+
+    /// Move the zero of a gauge unit to `reference`, in the SI unit.
+    /// Units that are not gauge units are returned unchanged.
+    pub const fn with_reference(&self, reference: f64) -> SiConversion {
+        if self.gauge {
+            SiConversion::new_gauge(self.scale, reference)
+        } else {
+            *self
+        }
+    }
+
+    /// Create a new SiConversion for a unit that shares the SI zero.
+    pub const fn linear(scale: f64) -> SiConversion {
+        SiConversion::new(scale, 0.0)
+    }
+
+    /// Get the conversion of differences between values in the unit,
+    /// which ignores the offset.
+    /// Example: a change of 9 °F is a change of 5 K.
+    pub const fn delta(&self) -> SiConversion {
+        SiConversion::linear(self.scale)
+    }
+
+    /// Convert a value in the unit to the SI unit.
+    pub const fn to_si(&self, value: f64) -> f64 {
+        self.convert(value, &SiConversion::SI)
+    }
+
+    /// Convert a value in the SI unit to the unit.
+    pub const fn from_si(&self, value: f64) -> f64 {
+        SiConversion::SI.convert(value, self)
+    }
+
+    /// Convert a value in the unit to another unit of the same dimension.
+    ///
+    /// Both directions between a pair of units use the same rounded factor,
+    /// multiplying one way and dividing the other, so converting there and
+    /// back returns the input within 1 ulp for units without an offset.
+    /// The factor is taken relative to the unit further from SI, which keeps
+    /// conversions to and from SI to a single rounding.
+    ///
+    /// Units with an offset (temperatures, gauge pressures) round the sum with
+    /// the offset too, so a round trip is only within 4 ulps of the largest of
+    /// the value and the two offsets, measured in this unit. Near an offset the
+    /// relative error can be large: -459.67 °F is 0 K, and comes back within
+    /// a few ulps of 459.67 rather than of the input.
+    pub const fn convert(&self, value: f64, to: &SiConversion) -> f64 {
+        if self.scale == to.scale && self.offset == to.offset {
+            return value;
+        }
+        let shifted = value + self.offset;
+        let converted = if self.multiplies_to(to) {
+            shifted * (self.scale / to.scale)
+        } else {
+            shifted / (to.scale / self.scale)
+        };
+        converted - to.offset
+    }
+
+    /// Check whether converting to `to` multiplies by the ratio of the scales,
+    /// rather than dividing by the inverse ratio.
+    /// Exactly one direction of every pair of distinct scales multiplies.
+    const fn multiplies_to(&self, to: &SiConversion) -> bool {
+        let from_distance = distance_from_one(self.scale);
+        let to_distance = distance_from_one(to.scale);
+        from_distance > to_distance || (from_distance == to_distance && self.scale > to.scale)
+    }
+}
+
+/// Get how far a scale is from 1, as a ratio that is at least 1.
+/// Example: 1000 for both 1000 and 0.001
+const fn distance_from_one(scale: f64) -> f64 {
+    if scale >= 1.0 { scale } else { 1.0 / scale }
+}
+
+/// Convert a finite value between two units of `dimension`.
+/// Pure arithmetic on the units' SiConversions, so it never allocates.
+fn convert_between(dimension: Dimension, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+    check_finite(value)?;
+    let from = unit_conversion(dimension, from_unit)?;
+    let to = unit_conversion(dimension, to_unit)?;
+    Ok(from.convert(value, &to))
+}
+
+/// Get the SiConversion of `unit`, which must measure `dimension`.
+fn unit_conversion(dimension: Dimension, unit: &UnitEnum) -> Result<SiConversion, UnitError> {
+    if unit.dimension() == dimension {
+        Ok(unit.si_conversion())
+    } else {
+        Err(UnitError::DimensionMismatch { expected: dimension, found: unit.dimension() })
+    }
+}
+
 /// Trait for a Unit of Measurement
 pub trait Unit: std::fmt::Debug + std::fmt::Display {
     /// Create a new instance of the Unit with the default value
+    #[allow(clippy::new_ret_no_self)]
     fn new() -> UnitEnum;
     
     /// Display the full name with abbreviation
-    /// Example: "Degrees Fehrenheit (°F)"
+    /// Example: "Feet (ft)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
-    /// Display the short name with abbreviation
-    /// Example: "Fehrenheit"
+    /// Display the short name without abbreviation
+    /// Example: "Feet"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
+    }
+
+    /// Display the name of one of the unit
+    /// Example: "Foot" for Feet, "Pound Per Square Inch" for Pounds Per Square Inch
+    fn name_singular(&self) -> String {
+        singular_name(&self.to_string())
     }
 
     /// Get the abbreviation of the unit
@@ -36,20 +704,46 @@ pub trait Unit: std::fmt::Debug + std::fmt::Display {
     /// Get the default unit for the given unit type
     fn default() -> UnitEnum;
 
+    /// Wrap the unit in its UnitEnum variant.
+    /// Example: `LengthUnit::Feet` -> `UnitEnum::Length(LengthUnit::Feet)`
+    fn unit_enum(&self) -> UnitEnum;
+
     /// Convert a value from one unit to another.
     /// The value is first converted to the default unit,
     /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64;
+    ///
+    /// Returns an error if either unit belongs to a different dimension,
+    /// if the value is NaN or infinite, or if the value is outside the
+    /// physical domain of the unit (e.g. below absolute zero).
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError>;
+
+    /// Convert a value from one unit to another.
+    /// Panicking wrapper around `try_convert`.
+    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
+        self.try_convert(value, from_unit, to_unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
 
     /// Create a new instance of the Unit from a &str input.
     /// The function supports name_full, name_short, and abbreviation.
-    fn from_str(&self, input: &str) -> UnitEnum;
+    ///
+    /// Returns `UnitError::UnknownUnit` if the input is not recognised.
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError>;
+
+    /// Create a new instance of the Unit from a &str input.
+    /// Panicking wrapper around `try_from_str`.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_str(&self, input: &str) -> UnitEnum {
+        self.try_from_str(input)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
 
 }
 
 // ------------------------------------------------------------
 
 /// Enum for LengthUnit
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum LengthUnit {
     Meters,
     Kilometers,
@@ -60,6 +754,25 @@ pub enum LengthUnit {
     Yards,
     Miles,
     NauticalMiles,
+    Mils,
+}
+
+impl LengthUnit {
+    /// Get how the unit relates to Meters, the SI unit of Length.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Meters => SiConversion::linear(1.0),
+            Self::Kilometers => SiConversion::linear(1000.0),
+            Self::Centimeters => SiConversion::linear(0.01),
+            Self::Millimeters => SiConversion::linear(0.001),
+            Self::Inches => SiConversion::linear(INCH),
+            Self::Feet => SiConversion::linear(FOOT),
+            Self::Yards => SiConversion::linear(YARD),
+            Self::Miles => SiConversion::linear(MILE),
+            Self::NauticalMiles => SiConversion::linear(NAUTICAL_MILE),
+            Self::Mils => SiConversion::linear(MIL),
+        }
+    }
 }
 
 /// Implementing Unit trait for LengthUnit
@@ -73,13 +786,13 @@ impl Unit for LengthUnit {
     /// Display the full name with abbreviation
     /// Example: "Meters (m)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Meters"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -95,47 +808,14 @@ impl Unit for LengthUnit {
             Self::Yards => String::from("yd"),
             Self::Miles => String::from("mi"),
             Self::NauticalMiles => String::from("Nmi"),
+            Self::Mils => String::from("mil"),
         }
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Meters,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_meters = match from_unit {
-            UnitEnum::Length(unit) => {
-                match unit.abbr().as_str() {
-                    "m" => value,
-                    "km" => value * 1000.0,
-                    "cm" => value * 0.01,
-                    "mm" => value * 0.001,
-                    "in" => value * 0.0254,
-                    "ft" => value * 0.3048,
-                    "yd" => value * 0.9144,
-                    "mi" => value * 1609.34,
-                    "Nmi" => value * 1852.0,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for LengthUnit conversion"),
-        };
-        match to_unit {
-            UnitEnum::Length(unit) => {
-                match unit.abbr().as_str() {
-                    "m" => value_meters,
-                    "km" => value_meters / 1000.0,
-                    "cm" => value_meters / 0.01,
-                    "mm" => value_meters / 0.001,
-                    "in" => value_meters / 0.0254,
-                    "ft" => value_meters / 0.3048,
-                    "yd" => value_meters / 0.9144,
-                    "mi" => value_meters / 1609.34,
-                    "Nmi" => value_meters / 1852.0,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for LengthUnit conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::LENGTH, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
@@ -144,11 +824,16 @@ impl Unit for LengthUnit {
         UnitEnum::Length(LengthUnit::Meters)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Length(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Meters" | "m" | "Meters (m)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Meters" | "m" | "Meters (m)" => UnitEnum::Length(LengthUnit::Meters),
             "Kilometers" | "km" | "Kilometers (km)" => UnitEnum::Length(LengthUnit::Kilometers),
             "Centimeters" | "cm" | "Centimeters (cm)" => UnitEnum::Length(LengthUnit::Centimeters),
@@ -158,8 +843,10 @@ impl Unit for LengthUnit {
             "Yards" | "yd" | "Yards (yd)" => UnitEnum::Length(LengthUnit::Yards),
             "Miles" | "mi" | "Miles (mi)" => UnitEnum::Length(LengthUnit::Miles),
             "Nautical Miles" | "Nmi" | "Nautical Miles (Nmi)" => UnitEnum::Length(LengthUnit::NauticalMiles),
-            _ => panic!("Invalid unit"),
-        }
+            "Mils" | "mil" | "Mils (mil)" => UnitEnum::Length(LengthUnit::Mils),
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 }
 
@@ -177,6 +864,7 @@ impl std::fmt::Display for LengthUnit {
             Self::Yards => write!(f, "Yards"),
             Self::Miles => write!(f, "Miles"),
             Self::NauticalMiles => write!(f, "Nautical Miles"),
+            Self::Mils => write!(f, "Mils"),
         }
     }
 }
@@ -192,6 +880,7 @@ impl std::fmt::Debug for LengthUnit {
 // ------------------------------------------------------------
 
 /// Enum for MassUnit
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum MassUnit {
     Kilograms,
     Grams,
@@ -201,6 +890,20 @@ pub enum MassUnit {
     Slugs,
 }
 
+impl MassUnit {
+    /// Get how the unit relates to Kilograms, the SI unit of Mass.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Kilograms => SiConversion::linear(1.0),
+            Self::Grams => SiConversion::linear(0.001),
+            Self::Milligrams => SiConversion::linear(0.000001),
+            Self::PoundsMass => SiConversion::linear(POUND),
+            Self::Ounces => SiConversion::linear(POUND / 16.0),
+            Self::Slugs => SiConversion::linear(POUND_FORCE / FOOT),
+        }
+    }
+}
+
 /// Implementing Unit trait for MassUnit
 impl Unit for MassUnit {
     /// Create a new instance of the Unit with the default value
@@ -211,13 +914,13 @@ impl Unit for MassUnit {
     /// Display the full name with abbreviation
     /// Example: "Kilograms (kg)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Kilograms"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -234,37 +937,9 @@ impl Unit for MassUnit {
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Kilograms,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_kilograms = match from_unit {
-            UnitEnum::Mass(unit) => {
-                match unit.abbr().as_str() {
-                    "kg" => value,
-                    "g" => value * 0.001,
-                    "mg" => value * 0.000001,
-                    "lbm" => value * 0.453592,
-                    "oz" => value * 0.0283495,
-                    "slugs" => value * 14.5939,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for MassUnit conversion"),
-        };
-        match to_unit {
-            UnitEnum::Mass(unit) => {
-                match unit.abbr().as_str() {
-                    "kg" => value_kilograms,
-                    "g" => value_kilograms / 0.001,
-                    "mg" => value_kilograms / 0.000001,
-                    "lbm" => value_kilograms / 0.453592,
-                    "oz" => value_kilograms / 0.0283495,
-                    "slugs" => value_kilograms / 14.5939,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for MassUnit conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::MASS, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
@@ -274,19 +949,25 @@ impl Unit for MassUnit {
         UnitEnum::Mass(MassUnit::Kilograms)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Mass(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Kilograms" | "kg" | "Kilograms (kg)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Kilograms" | "kg" | "Kilograms (kg)" => UnitEnum::Mass(MassUnit::Kilograms),
             "Grams" | "g" | "Grams (g)" => UnitEnum::Mass(MassUnit::Grams),
             "Milligrams" | "mg" | "Milligrams (mg)" => UnitEnum::Mass(MassUnit::Milligrams),
             "Pounds Mass" | "lbm" | "Pounds Mass (lbm)" => UnitEnum::Mass(MassUnit::PoundsMass),
             "Ounces" | "oz" | "Ounces (oz)" => UnitEnum::Mass(MassUnit::Ounces),
             "Slugs" | "slugs" | "Slugs (slugs)" => UnitEnum::Mass(MassUnit::Slugs),
-            _ => panic!("Invalid unit"),
-        }
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 }
 
@@ -318,6 +999,7 @@ impl std::fmt::Debug for MassUnit {
 // ------------------------------------------------------------
 
 /// Enum for TimeUnit
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TimeUnit {
     Seconds,
     Milliseconds,
@@ -329,6 +1011,22 @@ pub enum TimeUnit {
     Weeks,
 }
 
+impl TimeUnit {
+    /// Get how the unit relates to Seconds, the SI unit of Time.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Seconds => SiConversion::linear(1.0),
+            Self::Milliseconds => SiConversion::linear(0.001),
+            Self::Microseconds => SiConversion::linear(0.000001),
+            Self::Nanoseconds => SiConversion::linear(0.000000001),
+            Self::Minutes => SiConversion::linear(MINUTE),
+            Self::Hours => SiConversion::linear(HOUR),
+            Self::Days => SiConversion::linear(24.0 * HOUR),
+            Self::Weeks => SiConversion::linear(7.0 * 24.0 * HOUR),
+        }
+    }
+}
+
 /// Implementing Unit trait for TimeUnit
 impl Unit for TimeUnit {
     /// Create a new instance of the Unit with the default value
@@ -340,13 +1038,13 @@ impl Unit for TimeUnit {
     /// Display the full name with abbreviation
     /// Example: "Seconds (s)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Seconds"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -365,41 +1063,9 @@ impl Unit for TimeUnit {
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Seconds,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_seconds = match from_unit {
-            UnitEnum::Time(unit) => {
-                match unit.abbr().as_str() {
-                    "s" => value,
-                    "ms" => value * 0.001,
-                    "µs" => value * 0.000001,
-                    "ns" => value * 0.000000001,
-                    "min" => value * 60.0,
-                    "hr" => value * 3600.0,
-                    "days" => value * 86400.0,
-                    "weeks" => value * 604800.0,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for TimeUnit conversion"),
-        };
-        match to_unit {
-            UnitEnum::Time(unit) => {
-                match unit.abbr().as_str() {
-                    "s" => value_seconds,
-                    "ms" => value_seconds / 0.001,
-                    "µs" => value_seconds / 0.000001,
-                    "ns" => value_seconds / 0.000000001,
-                    "min" => value_seconds / 60.0,
-                    "hr" => value_seconds / 3600.0,
-                    "days" => value_seconds / 86400.0,
-                    "weeks" => value_seconds / 604800.0,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for TimeUnit conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::TIME, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
@@ -408,11 +1074,16 @@ impl Unit for TimeUnit {
         UnitEnum::Time(TimeUnit::Seconds)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Time(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Seconds" | "s" | "Seconds (s)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Seconds" | "s" | "Seconds (s)" => UnitEnum::Time(TimeUnit::Seconds),
             "Milliseconds" | "ms" | "Milliseconds (ms)" => UnitEnum::Time(TimeUnit::Milliseconds),
             "Microseconds" | "µs" | "Microseconds (µs)" => UnitEnum::Time(TimeUnit::Microseconds),
@@ -421,8 +1092,9 @@ impl Unit for TimeUnit {
             "Hours" | "hr" | "Hours (hr)" => UnitEnum::Time(TimeUnit::Hours),
             "Days" | "days" | "Days (days)" => UnitEnum::Time(TimeUnit::Days),
             "Weeks" | "weeks" | "Weeks (weeks)" => UnitEnum::Time(TimeUnit::Weeks),
-            _ => panic!("Invalid unit"),
-        }
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 }
 
@@ -455,6 +1127,7 @@ impl std::fmt::Debug for TimeUnit {
 
 
 /// Enum for TemperatureUnit
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TemperatureUnit {
     Kelvin,
     Celcius,
@@ -462,6 +1135,18 @@ pub enum TemperatureUnit {
     Fehrenheit,
 }
 
+impl TemperatureUnit {
+    /// Get how the unit relates to Kelvin, the SI unit of Temperature.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Celcius => SiConversion::new(1.0, 273.15),
+            Self::Kelvin => SiConversion::linear(1.0),
+            Self::Rankine => SiConversion::linear(5.0 / 9.0),
+            Self::Fehrenheit => SiConversion::new(5.0 / 9.0, 459.67),
+        }
+    }
+}
+
 /// Implementing Unit trait for TemperatureUnit
 impl Unit for TemperatureUnit {
     /// Create a new instance of the Unit with the default value
@@ -472,13 +1157,16 @@ impl Unit for TemperatureUnit {
     /// Display the full name with abbreviation
     /// Example: "Degrees Fehrenheit (°F)"
     fn name_full(&self) -> String {
-        String::from("Degrees {&self.fmt(f)} ({&self.abbr()})")
+        match self {
+            TemperatureUnit::Kelvin => format!("{self} ({})", self.abbr()),
+            _ => format!("Degrees {self} ({})", self.abbr()),
+        }
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Fehrenheit"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -493,33 +1181,16 @@ impl Unit for TemperatureUnit {
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Kelvin,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_kelvin = match from_unit {
-            UnitEnum::Temperature(unit) => {
-                match unit.abbr().as_str() {
-                    "°F" => (value + 459.67) * 5.0/9.0,
-                    "°C" => value + 273.15,
-                    "K" => value,
-                    "°R" => value * 5.0/9.0,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for Temperature conversion"),
-        };
-        match to_unit {
-            UnitEnum::Temperature(unit) => {
-                match unit.abbr().as_str() {
-                    "°F" => value_kelvin * 9.0/5.0 - 459.67,
-                    "°C" => value_kelvin - 273.15,
-                    "K" => value_kelvin,
-                    "°R" => value_kelvin * 9.0/5.0,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for Temperature conversion"),
+    /// The value is converted directly, by the ratio of the two units' degree sizes.
+    ///
+    /// Returns an error for values below absolute zero.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        let value_kelvin = convert_between(Dimension::TEMPERATURE, value, from_unit, &TemperatureUnit::default())?;
+        // nothing is colder than absolute zero
+        if value_kelvin < 0.0 {
+            return Err(UnitError::OutOfDomain { value, unit: from_unit.abbr() });
         }
+        convert_between(Dimension::TEMPERATURE, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
@@ -528,16 +1199,22 @@ impl Unit for TemperatureUnit {
         UnitEnum::Temperature(TemperatureUnit::Kelvin)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Temperature(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Fehrenheit" | "°F" | "Degrees Fehrenheit (°F)"=> UnitEnum::Temperature(TemperatureUnit::Fehrenheit),
             "Celcius" | "°C" | "Degrees Celcius (°C)" => UnitEnum::Temperature(TemperatureUnit::Celcius),
-            "Kelvin" | "K" | "Degrees Kelvin (K)" => UnitEnum::Temperature(TemperatureUnit::Kelvin),
+            "Kelvin" | "K" | "Kelvin (K)" | "Degrees Kelvin (K)" => UnitEnum::Temperature(TemperatureUnit::Kelvin),
             "Rankine" | "°R" | "Degrees Rankine (°R)" => UnitEnum::Temperature(TemperatureUnit::Rankine),
-            _ => panic!("Invalid unit"),
-        }
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 }
 
@@ -566,7 +1243,7 @@ impl std::fmt::Debug for TemperatureUnit {
 // ------------------------------------------------------------
 
 /// Enum for VelocityUnit
-#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum VelocityUnit {
     Knots,
     MilesPerHour,
@@ -575,6 +1252,19 @@ pub enum VelocityUnit {
     MetersPerSecond,
 }
 
+impl VelocityUnit {
+    /// Get how the unit relates to Meters Per Second, the SI unit of Velocity.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::MetersPerSecond => SiConversion::linear(1.0),
+            Self::FeetPerSecond => SiConversion::linear(FOOT),
+            Self::KilometersPerHour => SiConversion::linear(1000.0 / HOUR),
+            Self::MilesPerHour => SiConversion::linear(MILE / HOUR),
+            Self::Knots => SiConversion::linear(NAUTICAL_MILE / HOUR),
+        }
+    }
+}
+
 impl Unit for VelocityUnit {
     /// Create a new instance of the Unit with the default value
     /// Default unit is Meters Per Second
@@ -599,51 +1289,31 @@ impl Unit for VelocityUnit {
         UnitEnum::Velocity(VelocityUnit::MetersPerSecond)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Velocity(*self)
+    }
+
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Meters Per Second,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_mps = match from_unit {
-            UnitEnum::Velocity(unit) => {
-                match unit.abbr().as_str() {
-                    "m/s" => value,
-                    "ft/s" => value * 0.3048,
-                    "km/h" => value * 0.277778,
-                    "mph" => value * 0.44704,
-                    "Kts" => value * 0.514444,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for VelocityUnit conversion"),
-        };
-        match to_unit {
-            UnitEnum::Velocity(unit) => {
-                match unit.abbr().as_str() {
-                    "m/s" => value_mps,
-                    "ft/s" => value_mps * 3.28084,
-                    "km/h" => value_mps * 3.6,
-                    "mph" => value_mps * 2.23694,
-                    "Kts" => value_mps * 1.94384,
-                    _ => panic!("Invalid unit"),
-                }
-            },
-            _ => panic!("Invalid unit type for VelocityUnit conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::VELOCITY, value, from_unit, to_unit)
     }
 
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Knots" | "Kts" | "Knots (Kts)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Knots" | "Kts" | "Knots (Kts)" => UnitEnum::Velocity(VelocityUnit::Knots),
             "Miles Per Hour" | "mph" | "Miles Per Hour (mph)" => UnitEnum::Velocity(VelocityUnit::MilesPerHour),
             "Kilometers Per Hour" | "km/h" | "Kilometers Per Hour (km/h)" => UnitEnum::Velocity(VelocityUnit::KilometersPerHour),
             "Feet Per Second" | "ft/s" | "Feet Per Second (ft/s)" => UnitEnum::Velocity(VelocityUnit::FeetPerSecond),
             "Meters Per Second" | "m/s" | "Meters Per Second (m/s)" => UnitEnum::Velocity(VelocityUnit::MetersPerSecond),
-            _ => panic!("Invalid unit"),
-        }
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 }
 
@@ -671,12 +1341,24 @@ impl std::fmt::Debug for VelocityUnit {
 // ------------------------------------------------------------
 
 /// Enum for ForceUnit
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ForceUnit {
     Newtons,
     PoundsForce,
     KilogramsForce,
 }
 
+impl ForceUnit {
+    /// Get how the unit relates to Newtons, the SI unit of Force.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Newtons => SiConversion::linear(1.0),
+            Self::PoundsForce => SiConversion::linear(POUND_FORCE),
+            Self::KilogramsForce => SiConversion::linear(STANDARD_GRAVITY),
+        }
+    }
+}
+
 /// Implementing Unit trait for ForceUnit
 impl Unit for ForceUnit {
     /// Create a new instance of the Unit with the default value
@@ -687,13 +1369,13 @@ impl Unit for ForceUnit {
     /// Display the full name with abbreviation
     /// Example: "Newtons (N)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Newtons"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -707,27 +1389,9 @@ impl Unit for ForceUnit {
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Newtons,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_newtons = match from_unit {
-            UnitEnum::Force(unit) => match unit.abbr().as_str() {
-                "N" => value,
-                "lbf" => value * 4.44822,
-                "kgf" => value * 9.80665,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for ForceUnit conversion"),
-        };
-        match to_unit {
-            UnitEnum::Force(unit) => match unit.abbr().as_str() {
-                "N" => value_newtons,
-                "lbf" => value_newtons / 4.44822,
-                "kgf" => value_newtons / 9.80665,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for ForceUnit conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::FORCE, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
@@ -736,16 +1400,22 @@ impl Unit for ForceUnit {
         UnitEnum::Force(ForceUnit::Newtons)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Force(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Newtons" | "N" | "Newtons (N)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Newtons" | "N" | "Newtons (N)" => UnitEnum::Force(ForceUnit::Newtons),
             "Pounds Force" | "lbf" | "Pounds Force (lbf)" => UnitEnum::Force(ForceUnit::PoundsForce),
             "Kilograms Force" | "kgf" | "Kilograms Force (kgf)" => UnitEnum::Force(ForceUnit::KilogramsForce),
-            _ => panic!("Invalid unit"),
-        }
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 }
 
@@ -772,6 +1442,7 @@ impl std::fmt::Debug for ForceUnit {
 // ------------------------------------------------------------
 
 /// Enum for PressureUnit
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum PressureUnit {
     Pascals,
     Kilopascals,
@@ -782,6 +1453,29 @@ pub enum PressureUnit {
     Milibars,
     Atmospheres,
     PoundsPerSquareInch,
+    Bars,
+    PoundsPerSquareInchGauge,
+    BarsGauge,
+}
+
+impl PressureUnit {
+    /// Get how the unit relates to Pascals, the SI unit of Pressure.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Pascals => SiConversion::linear(1.0),
+            Self::Kilopascals => SiConversion::linear(1000.0),
+            Self::Megapascals => SiConversion::linear(1000000.0),
+            Self::Hectopascals => SiConversion::linear(100.0),
+            Self::InchesOfMercury => SiConversion::linear(INCH * 1000.0 * MILLIMETER_OF_MERCURY),
+            Self::MillimetersOfMercury => SiConversion::linear(MILLIMETER_OF_MERCURY),
+            Self::Milibars => SiConversion::linear(100.0),
+            Self::Atmospheres => SiConversion::linear(ATMOSPHERE),
+            Self::PoundsPerSquareInch => SiConversion::linear(POUND_FORCE / (INCH * INCH)),
+            Self::Bars => SiConversion::linear(BAR),
+            Self::PoundsPerSquareInchGauge => SiConversion::new_gauge(POUND_FORCE / (INCH * INCH), ATMOSPHERE),
+            Self::BarsGauge => SiConversion::new_gauge(BAR, ATMOSPHERE),
+        }
+    }
 }
 
 /// Implementing Unit trait for PressureUnit
@@ -794,13 +1488,13 @@ impl Unit for PressureUnit {
     /// Display the full name with abbreviation
     /// Example: "Pascals (Pa)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Pascals"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -814,45 +1508,18 @@ impl Unit for PressureUnit {
             Self::InchesOfMercury => String::from("inHg"),
             Self::MillimetersOfMercury => String::from("mmHg"),
             Self::Milibars => String::from("mbar"),
+            Self::Bars => String::from("bar"),
+            Self::PoundsPerSquareInchGauge => String::from("psig"),
+            Self::BarsGauge => String::from("barg"),
             Self::Atmospheres => String::from("atm"),
             Self::PoundsPerSquareInch => String::from("psi"),
         }
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Pascals,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_pascals = match from_unit {
-            UnitEnum::Pressure(unit) => match unit.abbr().as_str() {
-                "Pa" => value,
-                "kPa" => value * 1000.0,
-                "MPa" => value * 1000000.0,
-                "hPa" => value * 100.0,
-                "inHg" => value * 3386.39,
-                "mmHg" => value * 133.322,
-                "mbar" => value,
-                "atm" => value * 101325.0,
-                "psi" => value * 6894.76,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for Pressure conversion"),
-        };
-        match to_unit {
-            UnitEnum::Pressure(unit) => match unit.abbr().as_str() {
-                "Pa" => value_pascals,
-                "kPa" => value_pascals / 1000.0,
-                "MPa" => value_pascals / 1000000.0,
-                "hPa" => value_pascals / 100.0,
-                "inHg" => value_pascals / 3386.39,
-                "mmHg" => value_pascals / 133.322,
-                "mbar" => value_pascals,
-                "atm" => value_pascals / 101325.0,
-                "psi" => value_pascals / 6894.76,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for Pressure conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::PRESSURE, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
@@ -861,11 +1528,16 @@ impl Unit for PressureUnit {
         UnitEnum::Pressure(PressureUnit::Pascals)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Pressure(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Pascals" | "Pa" | "Pascals (Pa)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Pascals" | "Pa" | "Pascals (Pa)" => UnitEnum::Pressure(PressureUnit::Pascals),
             "Kilopascals" | "kPa" | "Kilopascals (kPa)" => UnitEnum::Pressure(PressureUnit::Kilopascals),
             "Megapascals" | "MPa" | "Megapascals (MPa)" => UnitEnum::Pressure(PressureUnit::Megapascals),
@@ -875,8 +1547,12 @@ impl Unit for PressureUnit {
             "Milibars" | "mbar" | "Milibars (mbar)" => UnitEnum::Pressure(PressureUnit::Milibars),
             "Atmospheres" | "atm" | "Atmospheres (atm)" => UnitEnum::Pressure(PressureUnit::Atmospheres),
             "Pounds Per Square Inch" | "psi" | "Pounds Per Square Inch (psi)" => UnitEnum::Pressure(PressureUnit::PoundsPerSquareInch),
-            _ => panic!("Invalid unit"),
-        }
+            "Bars" | "bar" | "Bars (bar)" => UnitEnum::Pressure(PressureUnit::Bars),
+            "Pounds Per Square Inch Gauge" | "psig" | "Pounds Per Square Inch Gauge (psig)" => UnitEnum::Pressure(PressureUnit::PoundsPerSquareInchGauge),
+            "Bars Gauge" | "barg" | "Bars Gauge (barg)" => UnitEnum::Pressure(PressureUnit::BarsGauge),
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 
 }
@@ -895,6 +1571,9 @@ impl std::fmt::Display for PressureUnit {
             Self::Milibars => write!(f, "Milibars"),
             Self::Atmospheres => write!(f, "Atmospheres"),
             Self::PoundsPerSquareInch => write!(f, "Pounds Per Square Inch"),
+            Self::Bars => write!(f, "Bars"),
+            Self::PoundsPerSquareInchGauge => write!(f, "Pounds Per Square Inch Gauge"),
+            Self::BarsGauge => write!(f, "Bars Gauge"),
         }
     }
 }
@@ -912,11 +1591,40 @@ impl std::fmt::Debug for PressureUnit {
 
 /// Enum for BearingUnit
 /// Radians are the default unit.
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum BearingUnit {
     Radians,
     Degrees,
+    ArcMinutes,
+    ArcSeconds,
     Gradians,
-    Mils,
+    Milliradians,
+    NatoMils,
+    WarsawPactMils,
+    SwedishMils,
+}
+
+impl BearingUnit {
+    /// The old name of `Milliradians`, from before the artillery mils were added.
+    #[deprecated(note = "use BearingUnit::Milliradians, or NatoMils for the mil of 1/6400 of a turn")]
+    #[allow(non_upper_case_globals)]
+    pub const Mils: BearingUnit = BearingUnit::Milliradians;
+
+    /// Get how the unit relates to Radians, the SI unit of Bearing.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::Radians => SiConversion::linear(1.0),
+            Self::Degrees => SiConversion::linear(std::f64::consts::PI / 180.0),
+            Self::ArcMinutes => SiConversion::linear(std::f64::consts::PI / 10800.0),
+            Self::ArcSeconds => SiConversion::linear(std::f64::consts::PI / 648000.0),
+            Self::Gradians => SiConversion::linear(std::f64::consts::PI / 200.0),
+            Self::Milliradians => SiConversion::linear(0.001),
+            // mils that divide the circle into 6400, 6000 and 6300
+            Self::NatoMils => SiConversion::linear(std::f64::consts::PI / 3200.0),
+            Self::WarsawPactMils => SiConversion::linear(std::f64::consts::PI / 3000.0),
+            Self::SwedishMils => SiConversion::linear(std::f64::consts::PI / 3150.0),
+        }
+    }
 }
 
 /// Implementing Unit trait for BearingUnit
@@ -932,13 +1640,13 @@ impl Unit for BearingUnit {
     /// Display the full name with abbreviation
     /// Example: "Degrees (°)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Degrees"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -947,57 +1655,50 @@ impl Unit for BearingUnit {
         match self {
             Self::Radians => String::from("rad"),
             Self::Degrees => String::from("°"),
+            Self::ArcMinutes => String::from("arcmin"),
+            Self::ArcSeconds => String::from("arcsec"),
             Self::Gradians => String::from("grad"),
-            Self::Mils => String::from("mil"),
+            Self::Milliradians => String::from("mrad"),
+            Self::NatoMils => String::from("mil6400"),
+            Self::WarsawPactMils => String::from("mil6000"),
+            Self::SwedishMils => String::from("mil6300"),
         }
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Degrees,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        // store the value in radians
-        let value_radians = match from_unit {
-            UnitEnum::Bearing(unit) => match unit.abbr().as_str() {
-                "rad" => value,
-                "°" => value * 0.0174533,
-                "grad" => value * 0.015708,
-                "mil" => value * 0.001,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for BearingUnit conversion"),
-        };
-
-        // convert from default (radians) to target unit
-        match to_unit {
-            UnitEnum::Bearing(unit) => match unit.abbr().as_str() {
-                "rad" => value_radians,
-                "°" => value_radians / 0.0174533,
-                "grad" => value_radians / 0.015708,
-                "mil" => value_radians / 0.001,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for BearingUnit conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::ANGLE, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
-    /// Default unit is Degrees
+    /// Default unit is Radians
     fn default() -> UnitEnum {
         UnitEnum::Bearing(BearingUnit::Radians)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Bearing(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Degrees" | "°" | "Degrees (°)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Degrees" | "°" | "Degrees (°)" => UnitEnum::Bearing(BearingUnit::Degrees),
             "Radians" | "rad" | "Radians (rad)" => UnitEnum::Bearing(BearingUnit::Radians),
+            "Arc Minutes" | "arcmin" | "Arc Minutes (arcmin)" => UnitEnum::Bearing(BearingUnit::ArcMinutes),
+            "Arc Seconds" | "arcsec" | "Arc Seconds (arcsec)" => UnitEnum::Bearing(BearingUnit::ArcSeconds),
             "Gradians" | "grad" | "Gradians (grad)" => UnitEnum::Bearing(BearingUnit::Gradians),
-            "Mils" | "mil" | "Mils (mil)" => UnitEnum::Bearing(BearingUnit::Mils),
-            _ => panic!("Invalid unit"),
-        }
+            "Milliradians" | "mrad" | "Milliradians (mrad)" => UnitEnum::Bearing(BearingUnit::Milliradians),
+            "NATO Mils" | "mil6400" | "NATO Mils (mil6400)" => UnitEnum::Bearing(BearingUnit::NatoMils),
+            "Warsaw Pact Mils" | "mil6000" | "Warsaw Pact Mils (mil6000)" => UnitEnum::Bearing(BearingUnit::WarsawPactMils),
+            "Swedish Mils" | "mil6300" | "Swedish Mils (mil6300)" => UnitEnum::Bearing(BearingUnit::SwedishMils),
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
 }
 
@@ -1010,8 +1711,13 @@ impl std::fmt::Display for BearingUnit {
         match self {
             Self::Degrees => write!(f, "Degrees"),
             Self::Radians => write!(f, "Radians"),
+            Self::ArcMinutes => write!(f, "Arc Minutes"),
+            Self::ArcSeconds => write!(f, "Arc Seconds"),
             Self::Gradians => write!(f, "Gradians"),
-            Self::Mils => write!(f, "Mils"),
+            Self::Milliradians => write!(f, "Milliradians"),
+            Self::NatoMils => write!(f, "NATO Mils"),
+            Self::WarsawPactMils => write!(f, "Warsaw Pact Mils"),
+            Self::SwedishMils => write!(f, "Swedish Mils"),
         }
     }
 }
@@ -1028,6 +1734,7 @@ impl std::fmt::Debug for BearingUnit {
 
 /// Enum for AccelerationUnit
 /// MetersPerSecondSquared is the default unit.
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AccelerationUnit {
     MetersPerSecondSquared,
     FeetPerSecondSquared,
@@ -1036,6 +1743,19 @@ pub enum AccelerationUnit {
     StandardGravity,
 }
 
+impl AccelerationUnit {
+    /// Get how the unit relates to Meters Per Second Squared, the SI unit of Acceleration.
+    pub const fn si_conversion(&self) -> SiConversion {
+        match self {
+            Self::MetersPerSecondSquared => SiConversion::linear(1.0),
+            Self::FeetPerSecondSquared => SiConversion::linear(FOOT),
+            Self::KilometersPerSecondSquared => SiConversion::linear(1000.0),
+            Self::MilesPerHourPerSecond => SiConversion::linear(MILE / HOUR),
+            Self::StandardGravity => SiConversion::linear(STANDARD_GRAVITY),
+        }
+    }
+}
+
 /// Implementing Unit trait for AccelerationUnit
 impl Unit for AccelerationUnit {
     /// Create a new instance of the Unit with the default value
@@ -1046,13 +1766,13 @@ impl Unit for AccelerationUnit {
     /// Display the full name with abbreviation
     /// Example: "Meters Per Second Squared (m/s²)"
     fn name_full(&self) -> String {
-        String::from("{&self.fmt(f)} ({&self.abbr()})")
+        format!("{self} ({})", self.abbr())
     }
 
     /// Display the short name without abbreviation.
     /// Example: "Meters Per Second Squared"
     fn name_short(&self) -> String {
-        String::from("{&self.fmt(f)}")
+        self.to_string()
     }
 
     /// Get the abbreviation of the unit.
@@ -1068,31 +1788,9 @@ impl Unit for AccelerationUnit {
     }
 
     /// Convert a value from one unit to another.
-    /// The value is first converted to Meters Per Second Squared,
-    /// then converted to the target unit.
-    fn convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> f64 {
-        let value_mps2 = match from_unit {
-            UnitEnum::Acceleration(unit) => match unit.abbr().as_str() {
-                "m/s²" => value,
-                "ft/s²" => value * 0.3048,
-                "km/s²" => value * 1000.0,
-                "mph/s" => value * 0.44704,
-                "g" => value * 9.80665,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for AccelerationUnit conversion"),
-        };
-        match to_unit {
-            UnitEnum::Acceleration(unit) => match unit.abbr().as_str() {
-                "m/s²" => value_mps2,
-                "ft/s²" => value_mps2 / 0.3048,
-                "km/s²" => value_mps2 / 1000.0,
-                "mph/s" => value_mps2 / 0.44704,
-                "g" => value_mps2 / 9.80665,
-                _ => panic!("Invalid unit"),
-            },
-            _ => panic!("Invalid unit type for AccelerationUnit conversion"),
-        }
+    /// The value is converted directly, by the ratio of the two units' sizes.
+    fn try_convert(&self, value: f64, from_unit: &UnitEnum, to_unit: &UnitEnum) -> Result<f64, UnitError> {
+        convert_between(Dimension::ACCELERATION, value, from_unit, to_unit)
     }
 
     /// Get the default unit for the given unit type
@@ -1101,18 +1799,24 @@ impl Unit for AccelerationUnit {
         UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared)
     }
 
+    /// Wrap the unit in its UnitEnum variant
+    fn unit_enum(&self) -> UnitEnum {
+        UnitEnum::Acceleration(*self)
+    }
+
     /// Create a new instance of the Unit from a &str input.
     /// The function supports full name, short name, and abbreviation.
     /// Example: "Meters Per Second Squared" | "m/s²" | "Meters Per Second Squared (m/s²)"
-    fn from_str(&self, input: &str) -> UnitEnum {
-        match input {
+    fn try_from_str(&self, input: &str) -> Result<UnitEnum, UnitError> {
+        let unit = match input {
             "Meters Per Second Squared" | "m/s²" | "Meters Per Second Squared (m/s²)" => UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared),
             "Feet Per Second Squared" | "ft/s²" | "Feet Per Second Squared (ft/s²)" => UnitEnum::Acceleration(AccelerationUnit::FeetPerSecondSquared),
             "Kilometers Per Second Squared" | "km/s²" | "Kilometers Per Second Squared (km/s²)" => UnitEnum::Acceleration(AccelerationUnit::KilometersPerSecondSquared),
             "Miles Per Hour Per Second" | "mph/s" | "Miles Per Hour Per Second (mph/s)" => UnitEnum::Acceleration(AccelerationUnit::MilesPerHourPerSecond),
             "Standard Gravity" | "g" | "Standard Gravity (g)" => UnitEnum::Acceleration(AccelerationUnit::StandardGravity),
-            _ => panic!("Invalid unit"),
-        }
+            _ => return Err(UnitError::UnknownUnit(String::from(input))),
+        };
+        Ok(unit)
     }
     
 }
@@ -1141,4 +1845,163 @@ impl std::fmt::Debug for AccelerationUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{self}")
     }
-}
\ No newline at end of file
+}
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_names() {
+        assert_eq!(LengthUnit::Feet.name_full(), "Feet (ft)");
+        assert_eq!(LengthUnit::Feet.name_short(), "Feet");
+        assert_eq!(LengthUnit::Feet.name_singular(), "Foot");
+        assert_eq!(PressureUnit::InchesOfMercury.name_singular(), "Inch Of Mercury");
+        assert_eq!(TemperatureUnit::Fehrenheit.name_full(), "Degrees Fehrenheit (°F)");
+        assert_eq!(TemperatureUnit::Kelvin.name_full(), "Kelvin (K)");
+        assert_eq!(UnitEnum::Velocity(VelocityUnit::FeetPerSecond).name_singular(), "Foot Per Second");
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        fn check<U: Unit>(unit: &U) {
+            for name in [unit.name_full(), unit.name_short(), unit.abbr()] {
+                assert_eq!(unit.try_from_str(&name), Ok(unit.unit_enum()), "{name}");
+            }
+        }
+        for unit in UnitEnum::all() {
+            match unit {
+                UnitEnum::Length(unit) => check(unit),
+                UnitEnum::Mass(unit) => check(unit),
+                UnitEnum::Time(unit) => check(unit),
+                UnitEnum::Temperature(unit) => check(unit),
+                UnitEnum::Velocity(unit) => check(unit),
+                UnitEnum::Force(unit) => check(unit),
+                UnitEnum::Pressure(unit) => check(unit),
+                UnitEnum::Bearing(unit) => check(unit),
+                UnitEnum::Acceleration(unit) => check(unit),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_unit_enum() {
+        assert_eq!("km".parse(), Ok(UnitEnum::Length(LengthUnit::Kilometers)));
+        assert_eq!("Meters (m)".parse(), Ok(UnitEnum::Length(LengthUnit::Meters)));
+        for name in ["meter", "Meters", "metre", "METRES", " meters "] {
+            assert_eq!(name.parse(), Ok(UnitEnum::Length(LengthUnit::Meters)), "{name}");
+        }
+        assert_eq!("foot".parse(), Ok(UnitEnum::Length(LengthUnit::Feet)));
+        assert_eq!("inch".parse(), Ok(UnitEnum::Length(LengthUnit::Inches)));
+        assert_eq!("nautical mile".parse(), Ok(UnitEnum::Length(LengthUnit::NauticalMiles)));
+        assert_eq!("mile per hour".parse(), Ok(UnitEnum::Velocity(VelocityUnit::MilesPerHour)));
+        assert_eq!("KPA".parse(), Ok(UnitEnum::Pressure(PressureUnit::Kilopascals)));
+    }
+
+    #[test]
+    fn test_parse_unit_enum_fallbacks() {
+        assert_eq!("deg".parse(), Ok(UnitEnum::Bearing(BearingUnit::Degrees)));
+        assert_eq!("degF".parse(), Ok(UnitEnum::Temperature(TemperatureUnit::Fehrenheit)));
+        assert_eq!("us".parse(), Ok(UnitEnum::Time(TimeUnit::Microseconds)));
+        assert_eq!("μs".parse(), Ok(UnitEnum::Time(TimeUnit::Microseconds)));
+        assert_eq!("m/s^2".parse(), Ok(UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared)));
+        assert_eq!("lb".parse(), Ok(UnitEnum::Mass(MassUnit::PoundsMass)));
+        for name in ["Celcius", "Celsius", "degree celsius", "°C"] {
+            assert_eq!(name.parse(), Ok(UnitEnum::Temperature(TemperatureUnit::Celcius)), "{name}");
+        }
+        for name in ["Fehrenheit", "fahrenheit", "Degrees Fahrenheit"] {
+            assert_eq!(name.parse(), Ok(UnitEnum::Temperature(TemperatureUnit::Fehrenheit)), "{name}");
+        }
+        assert_eq!("furlongs".parse::<UnitEnum>(), Err(UnitError::UnknownUnit(String::from("furlongs"))));
+    }
+
+    #[test]
+    fn test_parse_ambiguous_units() {
+        assert_eq!(
+            "g".parse::<UnitEnum>(),
+            Err(UnitError::AmbiguousUnit {
+                input: String::from("g"),
+                candidates: vec![
+                    UnitEnum::Mass(MassUnit::Grams),
+                    UnitEnum::Acceleration(AccelerationUnit::StandardGravity)
+                ],
+            })
+        );
+        assert!(matches!("mil".parse::<UnitEnum>(), Err(UnitError::AmbiguousUnit { .. })));
+        assert!(matches!("Mils".parse::<UnitEnum>(), Err(UnitError::AmbiguousUnit { .. })));
+        assert_eq!("thou".parse(), Ok(UnitEnum::Length(LengthUnit::Mils)));
+
+        assert_eq!(UnitEnum::parse_as(Dimension::LENGTH, "mil"), Ok(UnitEnum::Length(LengthUnit::Mils)));
+        assert!(matches!(UnitEnum::parse_as(Dimension::ANGLE, "mil"), Err(UnitError::AmbiguousUnit { candidates, .. }) if candidates.len() == 4));
+        assert!(matches!(UnitEnum::parse_as(Dimension::ANGLE, "mils"), Err(UnitError::AmbiguousUnit { .. })));
+        assert_eq!("NATO mils".parse(), Ok(UnitEnum::Bearing(BearingUnit::NatoMils)));
+        assert_eq!("WP mil".parse(), Ok(UnitEnum::Bearing(BearingUnit::WarsawPactMils)));
+        assert_eq!("mil6300".parse(), Ok(UnitEnum::Bearing(BearingUnit::SwedishMils)));
+        assert_eq!("streck".parse(), Ok(UnitEnum::Bearing(BearingUnit::SwedishMils)));
+        assert_eq!("mrad".parse(), Ok(UnitEnum::Bearing(BearingUnit::Milliradians)));
+        #[allow(deprecated)]
+        let old_mils = BearingUnit::Mils;
+        assert_eq!(old_mils, BearingUnit::Milliradians);
+        assert_eq!(UnitEnum::parse_as(Dimension::MASS, "Grams"), Ok(UnitEnum::Mass(MassUnit::Grams)));
+        assert_eq!(
+            UnitEnum::parse_as(Dimension::LENGTH, "kg"),
+            Err(UnitError::DimensionMismatch { expected: Dimension::LENGTH, found: Dimension::MASS })
+        );
+        assert_eq!(
+            UnitEnum::parse_as(Dimension::LENGTH, "furlongs"),
+            Err(UnitError::UnknownUnit(String::from("furlongs")))
+        );
+    }
+
+    #[test]
+    fn test_exact_constants() {
+        let mile = UnitEnum::Length(LengthUnit::Miles);
+        assert_eq!(mile.try_to_si(1.0), Ok(1609.344));
+        let millibar = UnitEnum::Pressure(PressureUnit::Milibars);
+        assert_eq!(millibar.try_to_si(1013.25), Ok(101325.0));
+        let knots = UnitEnum::Velocity(VelocityUnit::Knots);
+        assert_eq!(knots.try_from_si(1852.0 / 3600.0), Ok(1.0));
+        let degrees = UnitEnum::Bearing(BearingUnit::Degrees);
+        assert_eq!(degrees.try_to_si(180.0), Ok(std::f64::consts::PI));
+        let pounds_force = UnitEnum::Force(ForceUnit::PoundsForce);
+        assert_eq!(pounds_force.scale(), 0.45359237 * 9.80665);
+        assert_eq!(UnitEnum::Length(LengthUnit::Yards).scale(), 0.9144);
+        assert_eq!(UnitEnum::Length(LengthUnit::Mils).scale(), 0.0000254);
+    }
+
+    #[test]
+    fn test_round_trip_within_one_ulp() {
+        let values = [1.0, -3.5, 0.1, 12345.678, 6.02e23, 1.0e-9];
+        let linear = || UnitEnum::all().iter().filter(|unit| unit.si_conversion().offset == 0.0);
+        for from in linear() {
+            for to in linear().filter(|unit| unit.dimension() == from.dimension()) {
+                for value in values {
+                    let there = from.si_conversion().convert(value, &to.si_conversion());
+                    let back = to.si_conversion().convert(there, &from.si_conversion());
+                    let ulp = f64::EPSILON * value.abs();
+                    assert!((back - value).abs() <= ulp, "{value} {from} -> {to} -> {back}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_offset_round_trip_within_four_ulps() {
+        let values = [1.0, -3.5, 0.1, 12345.678, 6.02e23, 1.0e-9, -459.67, 101.325];
+        let offset = || UnitEnum::all().iter().filter(|unit| unit.si_conversion().offset != 0.0);
+        for from in offset() {
+            for to in UnitEnum::all().iter().filter(|unit| unit.dimension() == from.dimension()) {
+                let (there_conversion, back_conversion) = (from.si_conversion(), to.si_conversion());
+                // the offset of `to`, in `from`
+                let to_offset = back_conversion.offset * back_conversion.scale / there_conversion.scale;
+                for value in values {
+                    let there = there_conversion.convert(value, &back_conversion);
+                    let back = back_conversion.convert(there, &there_conversion);
+                    let largest = value.abs().max(there_conversion.offset.abs()).max(to_offset.abs());
+                    let ulps = 4.0 * f64::EPSILON * largest;
+                    assert!((back - value).abs() <= ulps, "{value} {from} -> {to} -> {back}");
+                }
+            }
+        }
+    }
+}
@@ -1,678 +1,1185 @@
 #![allow(dead_code)]
+use crate::bearing::BearingValue;
+use crate::dimension::Dimension;
+use crate::error::UnitError;
+use crate::format::{Compound, Humanize, ValueFormat};
+use crate::parse::parse_quantity_as;
+use crate::system::{UnitSystem, UnitValue};
 use crate::units::*;
 
-trait Value: Sized{
-    /// Get the value in the given unit.
+/// Trait for a Value measured in a Unit
+///
+/// Every Value stores its magnitude in the SI (default) unit of its dimension.
+/// The `value_in` and `get` families convert the stored magnitude to the
+/// requested unit, and `new` and `set` convert the given magnitude from it.
+pub trait Value: Sized{
+    /// The unit enum the Value is measured in.
+    /// Example: `LengthUnit` for `LengthValue`
+    type Unit: Unit;
+
+    /// Create a new Value from a magnitude already in the default unit.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let length = LengthValue::from_si_value(100.0);
+    /// assert_eq!(length.value_in(&LengthUnit::Kilometers), 0.1);
+    /// ```
+    fn from_si_value(value: f64) -> Self;
+
+    /// Get the stored magnitude, in the default unit.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let length = LengthValue::new(2.0, &LengthUnit::Kilometers);
+    /// assert_eq!(length.si_value(), 2000.0);
+    /// ```
+    fn si_value(&self) -> f64;
+
+    /// Get the default unit the magnitude is stored in.
+    fn unit_default() -> UnitEnum;
+
+    /// Get the magnitude of the value in the given unit.
     /// Behind the scenes, the value is stored in the default unit,
     /// so the value is converted to the given unit before returning.
-    /// The conversion uses the `convert` function of the Unit.
-    /// 
+    /// The conversion uses the `try_convert` function of the Unit.
+    fn try_value_in(&self, unit: &Self::Unit) -> Result<f64, UnitError>;
+
+    /// Get the magnitude of the value in the given unit.
+    /// Panicking wrapper around `try_value_in`.
+    ///
     /// # Example
     /// ```rust
-    /// let length = LengthValue::new(100.0, &UnitEnum::Length(LengthUnit::Meters));
-    /// assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Meters)).value, 100.0);
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let length = LengthValue::new(1.0, &LengthUnit::Kilometers);
+    /// assert_eq!(length.value_in(&LengthUnit::Meters), 1000.0);
     /// ```
-    fn get(&self, unit: &UnitEnum) -> Self;
+    fn value_in(&self, unit: &Self::Unit) -> f64 {
+        self.try_value_in(unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Get the value in the given unit, as a Value whose `si_value` holds the
+    /// magnitude in the given unit rather than in the SI unit.
+    #[deprecated(note = "the result is not in the SI unit, use try_value_in for the magnitude in a unit")]
+    fn try_get(&self, unit: &Self::Unit) -> Result<Self, UnitError> {
+        Ok(Self::from_si_value(self.try_value_in(unit)?))
+    }
+
+    /// Set self to the given value in the given unit.
+    /// The conversion uses the `try_convert` function of the Unit.
+    ///
+    /// Returns an error if the value is not finite or out of the unit's domain.
+    /// On error the stored value is left unchanged.
+    fn try_set(&mut self, value: f64, unit: &Self::Unit) -> Result<(), UnitError>;
+
+    /// Create a new Value with the given value and unit.
+    /// The conversion uses the `try_convert` function of the Unit.
+    ///
+    /// Returns an error if the value is not finite or out of the unit's domain.
+    fn try_new(value: f64, unit: &Self::Unit) -> Result<Self, UnitError>;
+
+    /// Get the value in the given unit, as a Value whose `si_value` holds the
+    /// magnitude in the given unit rather than in the SI unit.
+    /// Panicking wrapper around `try_get`.
+    #[deprecated(note = "the result is not in the SI unit, use value_in for the magnitude in a unit")]
+    fn get(&self, unit: &Self::Unit) -> Self {
+        #[allow(deprecated)]
+        self.try_get(unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
     /// Set self to the given value in the given unit.
     /// Behind the scenes, the value is stored in the default unit,
     /// so the value is converted to the default unit before storing.
     /// The conversion uses the `convert` function of the Unit.
-    /// 
+    ///
     /// # Example
     /// ```rust
-    /// let mut length = LengthValue::new(100.0, &UnitEnum::Length(LengthUnit::Meters));
-    /// length.set(200.0, &UnitEnum::Length(LengthUnit::Meters));
-    /// assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Meters)).value, 200.0);
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let mut length = LengthValue::new(100.0, &LengthUnit::Meters);
+    /// length.set(200.0, &LengthUnit::Meters);
+    /// assert_eq!(length.value_in(&LengthUnit::Meters), 200.0);
     /// ```
-    fn set(&mut self, value: f64, unit: &UnitEnum);
+    fn set(&mut self, value: f64, unit: &Self::Unit) {
+        self.try_set(value, unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
 
     /// Create a new Value with the given value and unit.
     /// The value is stored in the default unit.
     /// The value is converted to the default unit before storing.
-    /// 
+    ///
     /// # Example
     /// ```rust
-    /// let length = LengthValue::new(100.0, &UnitEnum::Length(LengthUnit::Meters));
-    /// assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Meters)).value, 100.0);
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let length = LengthValue::new(100.0, &LengthUnit::Meters);
+    /// assert_eq!(length.value_in(&LengthUnit::Meters), 100.0);
     /// ```
-    fn new(value: f64, unit: &UnitEnum) -> Self;
-}
+    fn new(value: f64, unit: &Self::Unit) -> Self {
+        Self::try_new(value, unit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
 
-// ---------------------------------------------------------
+    /// Get the magnitude of the value in a unit only known at runtime,
+    /// such as one of the units of a `UnitSystem`.
+    /// Only the scale of the unit applies, as for an amount; values measured
+    /// from a zero, such as `Absolute`, apply the offset too.
+    ///
+    /// Returns an error if the unit measures a different dimension.
+    fn try_value_in_unit(&self, unit: &UnitEnum) -> Result<f64, UnitError> {
+        Self::unit_default().try_convert_delta_to(self.si_value(), unit)
+    }
 
-/// LengthValue struct
-/// 
-/// The value is stored in Meters by default.
-pub struct LengthValue {
-    /// value of length
-    value:f64,
-}
+    /// Write the value in the most readable metric unit, as in "0.42 mm"
+    /// or "1 day 2 hr 3 min 4 s". See `Humanize` for how the unit is chosen.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let length = LengthValue::new(1500.0, &LengthUnit::Meters);
+    /// assert_eq!(length.humanize(), "1.5 km");
+    /// ```
+    fn humanize(&self) -> String {
+        Humanize::new().format(self)
+    }
 
-/// Implementing Value trait for LengthValue
-impl Value for LengthValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Meters,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> LengthValue {
-        // use the conversion function of the Unit
-        LengthValue { 
-            value: match unit {
-                UnitEnum::Length(length_unit) => length_unit.convert(
-                    self.value,
-                    &LengthUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for LengthValue"),
-            }
-        }
+    /// Write the value in the most readable unit, with the given options.
+    fn humanize_with(&self, humanize: &Humanize) -> String {
+        humanize.format(self)
     }
 
-    /// set self to the given value in the given unit
-    /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Meters before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Meters and store it
-        self.value = match unit {
-            UnitEnum::Length(length_unit) => length_unit.convert(
-                value,
-                unit,
-                &LengthUnit::default()
-            ),
-            _ => panic!("Invalid unit for LengthValue"),
-        };
-    }
-
-    /// Create a new LengthValue with the given value and unit.
-    /// The value is stored in Meters by default.
-    /// The value is converted to Meters before storing.
-    /// 
+    /// Get the value in the preferred unit of a unit system for its dimension.
+    ///
+    /// Returns an error if the value cannot be converted to that unit.
+    fn try_to_system(&self, system: &UnitSystem) -> Result<UnitValue, UnitError> {
+        let unit = system.preferred(Self::unit_default().dimension()).unwrap_or_else(Self::unit_default);
+        Ok(UnitValue::new(self.try_value_in_unit(&unit)?, unit))
+    }
+
+    /// Get the value in the preferred unit of a unit system for its dimension.
+    /// Panicking wrapper around `try_to_system`.
+    ///
     /// # Example
     /// ```rust
-    /// let length = LengthValue::new(100.0, &UnitEnum::Length(LengthUnit::Meters));
-    /// assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Meters)).value, 100.0);
+    /// use units_and_values::system::*;
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let speed = VelocityValue::new(100.0, &VelocityUnit::KilometersPerHour);
+    /// assert_eq!(format!("{:.1}", speed.to_system(&UnitSystem::aviation())), "54.0 Kts");
     /// ```
-    fn new(value: f64, unit: &UnitEnum) -> LengthValue {
-        LengthValue {
-            value: match unit {
-                UnitEnum::Length(length_unit) => length_unit.convert(
-                    value,
-                    unit,
-                    &LengthUnit::default()
-                ),
-                _ => panic!("Invalid unit for LengthValue"),
-            }
+    fn to_system(&self, system: &UnitSystem) -> UnitValue {
+        self.try_to_system(system)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Round the value to the nearest multiple of `increment` in the given unit.
+    /// Example: 12.3 knots to the nearest 5 knots -> 10 knots
+    ///
+    /// Returns an error if the increment is not a positive finite number.
+    fn try_round_to(&self, increment: f64, unit: &Self::Unit) -> Result<Self, UnitError>
+    where
+        Self: Clone,
+    {
+        if !(increment.is_finite() && increment > 0.0) {
+            return Err(UnitError::OutOfDomain { value: increment, unit: unit.abbr() });
         }
+        let mut rounded = self.clone();
+        rounded.try_set(round_to_increment(self.try_value_in(unit)?, increment), unit)?;
+        Ok(rounded)
+    }
+
+    /// Round the value to the nearest multiple of `increment` in the given unit.
+    /// Panicking wrapper around `try_round_to`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let setting = PressureValue::new(29.9237, &PressureUnit::InchesOfMercury);
+    /// assert_eq!(setting.round_to(0.01, &PressureUnit::InchesOfMercury).value_in(&PressureUnit::InchesOfMercury), 29.92);
+    /// ```
+    fn round_to(&self, increment: f64, unit: &Self::Unit) -> Self
+    where
+        Self: Clone,
+    {
+        self.try_round_to(increment, unit)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
+    /// Write the value split over the given units, largest first, rounding
+    /// the last part to a whole number. See `Compound` for more options.
+    ///
+    /// Returns an error if a unit has an offset, as the temperature scales do.
+    fn try_format_compound(&self, units: &[Self::Unit]) -> Result<String, UnitError> {
+        let units: Vec<UnitEnum> = units.iter().map(|unit| unit.unit_enum()).collect();
+        Compound::try_new(&units)?.try_format(self)
+    }
+
+    /// Write the value split over the given units, largest first.
+    /// Panicking wrapper around `try_format_compound`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let time = TimeValue::new(83.0, &TimeUnit::Minutes);
+    /// assert_eq!(time.format_compound(&[TimeUnit::Hours, TimeUnit::Minutes]), "1 hr 23 min");
+    /// ```
+    fn format_compound(&self, units: &[Self::Unit]) -> String {
+        self.try_format_compound(units)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Round a number to the nearest multiple of `increment`.
+/// Divides by the inverse of decimal increments such as 0.01,
+/// so 29.92 comes out as 29.92 rather than 29.920000000000002.
+fn round_to_increment(value: f64, increment: f64) -> f64 {
+    let steps = (value / increment).round();
+    let inverse = 1.0 / increment;
+    if inverse >= 1.0 && (inverse - inverse.round()).abs() < 1e-9 * inverse {
+        steps / inverse.round()
+    } else {
+        steps * increment
+    }
 }
 
 // ---------------------------------------------------------
 
-/// MassValue struct
-pub struct MassValue {
-    /// value of mass
-    value:f64,
+/// Quantity struct
+///
+/// A value whose physical dimension is part of its type. The const parameters
+/// are the exponents of the base dimensions, in order:
+/// Length (`L`), Mass (`M`), Time (`T`), Temperature (`TH`),
+/// Electric Current (`I`), Amount of Substance (`N`),
+/// Luminous Intensity (`J`), and Angle (`A`).
+///
+/// Example: Velocity is `Quantity<1, 0, -1, 0, 0, 0, 0, 0>` (m/s).
+///
+/// The value is always stored in the SI unit of the dimension.
+/// Quantities of different dimensions are different types, so they cannot be
+/// mixed up, and passing a unit of the wrong dimension does not compile:
+///
+/// ```compile_fail
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let length = LengthValue::new(100.0, &MassUnit::Kilograms);
+/// ```
+pub struct Quantity<
+    const L: i8,
+    const M: i8,
+    const T: i8,
+    const TH: i8,
+    const I: i8,
+    const N: i8,
+    const J: i8,
+    const A: i8,
+> {
+    /// value in the SI unit of the dimension
+    value: f64,
 }
 
-/// Implementing Value trait for MassValue
-/// 
-/// The value is stored in Kilograms by default.
-impl Value for MassValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Kilograms,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> MassValue {
-        // use the conversion function of the Unit
-        MassValue { 
-            value: match unit {
-                UnitEnum::Mass(mass_unit) => mass_unit.convert(
-                    self.value,
-                    &MassUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for MassValue"),
-            }
-        }
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8>
+    Quantity<L, M, T, TH, I, N, J, A>
+{
+    /// The dimension exponents of the Quantity, in the order of the const parameters.
+    pub const EXPONENTS: [i8; 8] = [L, M, T, TH, I, N, J, A];
+
+    /// The Dimension of the Quantity.
+    pub const DIMENSION: Dimension = Dimension::from_exponents(Self::EXPONENTS);
+
+    /// Create a new Quantity from a value in the SI unit of the dimension.
+    pub fn from_si_value(value: f64) -> Self {
+        Quantity { value }
     }
 
-    /// set self to the given value in the given unit
-    /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Kilograms before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Kilograms and store it
-        self.value = match unit {
-            UnitEnum::Mass(mass_unit) => mass_unit.convert(
-                value,
-                unit,
-                &MassUnit::default()
-            ),
-            _ => panic!("Invalid unit for MassValue"),
-        };
-    }
-
-    /// Create a new MassValue with the given value and unit.
-    /// The value is stored in Kilograms by default.
-    /// The value is converted to Kilograms before storing.
-    /// 
+    /// Get the value in the SI unit of the dimension.
+    pub fn si_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Parse a Quantity written as a number followed by a unit.
+    /// See `parse::parse_quantity` for the accepted formats.
+    ///
+    /// Only units of this dimension are considered,
+    /// so "3 g" parses as Grams for a MassValue and as Standard Gravity for an AccelerationValue.
+    ///
+    /// Returns an error if the text does not parse,
+    /// or if the unit measures a different dimension.
+    ///
     /// # Example
     /// ```rust
-    /// let mass = MassValue::new(100.0, &UnitEnum::Mass(MassUnit::Kilograms));
-    /// assert_eq!(mass.get(&UnitEnum::Mass(MassUnit::Kilograms)).value, 100.0);
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let length = LengthValue::parse("12.5 km").unwrap();
+    /// assert_eq!(length.si_value(), 12500.0);
+    /// assert!(PressureValue::parse("12.5 km").is_err());
     /// ```
-    fn new(value: f64, unit: &UnitEnum) -> MassValue {
-        MassValue {
-            value: match unit {
-                UnitEnum::Mass(mass_unit) => mass_unit.convert(
-                    value,
-                    unit,
-                    &MassUnit::default()
-                ),
-                _ => panic!("Invalid unit for MassValue"),
-            }
-        }
+    pub fn parse(input: &str) -> Result<Self, UnitError> {
+        let parsed = parse_quantity_as(Self::DIMENSION, input)?;
+        // an amount, so "10 °C" is a change of 10 K even for a TemperatureDelta
+        let value = parsed.unit().si_conversion().delta().to_si(parsed.value());
+        Ok(Self::from_si_value(value))
     }
+}
+
+/// Implementing FromStr trait for Quantity
+/// Same as `Quantity::parse`.
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8>
+    std::str::FromStr for Quantity<L, M, T, TH, I, N, J, A>
+{
+    type Err = UnitError;
 
+    fn from_str(input: &str) -> Result<Self, UnitError> {
+        Self::parse(input)
+    }
 }
 
-// ---------------------------------------------------------
+/// Links a Quantity to the unit enum its dimension is measured in.
+///
+/// Only Quantities with a unit enum implement `Value`; others (such as the
+/// product of a mass and a length) can still be computed and read in SI units.
+pub trait MeasuredIn {
+    /// The unit enum of the dimension.
+    type Unit: Unit;
+}
 
-/// TimeValue struct
-/// 
-/// The value is stored in Seconds by default.
-pub struct TimeValue {
-    /// value of time
-    value:f64,
+/// Links a unit enum to the Quantity it measures.
+/// This is the reverse of `MeasuredIn`.
+pub trait Measures: Unit {
+    /// The Quantity measured by the unit enum.
+    type Quantity: Value<Unit = Self>;
 }
 
-/// Implementing Value trait for TimeValue
-/// 
-/// The value is stored in Seconds by default.
-impl Value for TimeValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Seconds,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> TimeValue {
-        // use the conversion function of the Unit
-        TimeValue { 
-            value: match unit {
-                UnitEnum::Time(time_unit) => time_unit.convert(
-                    self.value,
-                    &TimeUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for TimeValue"),
-            }
+/// The Quantity measured by the unit enum `U`.
+/// Example: `Measurement<LengthUnit>` is `LengthValue`.
+pub type Measurement<U> = <U as Measures>::Quantity;
+
+/// Declares the unit enum of a dimension.
+macro_rules! measured_in {
+    ($unit:ty => $quantity:ty) => {
+        impl MeasuredIn for $quantity {
+            type Unit = $unit;
         }
+
+        impl Measures for $unit {
+            type Quantity = $quantity;
+        }
+    };
+}
+
+/// Implementing Value trait for every Quantity that has a unit enum
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> Value
+    for Quantity<L, M, T, TH, I, N, J, A>
+where
+    Self: MeasuredIn,
+{
+    type Unit = <Self as MeasuredIn>::Unit;
+
+    /// Create a new Quantity from a value in the default unit.
+    fn from_si_value(value: f64) -> Self {
+        Quantity { value }
+    }
+
+    /// Get the value in the default unit.
+    fn si_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the unit the value is stored in.
+    fn unit_default() -> UnitEnum {
+        <Self::Unit as Unit>::default()
+    }
+
+    /// Get the magnitude of the value in the given unit.
+    /// Behind the scenes, the value is stored in the default unit,
+    /// so the value is converted to the given unit before returning.
+    /// A Quantity is an amount, so only the scale of the unit applies, never an offset.
+    fn try_value_in(&self, unit: &Self::Unit) -> Result<f64, UnitError> {
+        Self::unit_default().try_convert_delta_to(self.value, &unit.unit_enum())
     }
 
     /// set self to the given value in the given unit
     /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Seconds before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Seconds and store it
-        self.value = match unit {
-            UnitEnum::Time(time_unit) => time_unit.convert(
-                value,
-                unit,
-                &TimeUnit::default()
-            ),
-            _ => panic!("Invalid unit for TimeValue"),
-        };
-    }
-
-    /// Create a new TimeValue with the given value and unit.
-    /// The value is stored in Seconds by default.
-    /// The value is converted to Seconds before storing.
-    /// 
-    /// # Example
-    /// ```rust
-    /// let time = TimeValue::new(100.0, &UnitEnum::Time(TimeUnit::Seconds));
-    /// assert_eq!(time.get(&UnitEnum::Time(TimeUnit::Seconds)).value, 100.0);
-    /// ```
-    fn new(value: f64, unit: &UnitEnum) -> TimeValue {
-        TimeValue {
-            value: match unit {
-                UnitEnum::Time(time_unit) => time_unit.convert(
-                    value,
-                    unit,
-                    &TimeUnit::default()
-                ),
-                _ => panic!("Invalid unit for TimeValue"),
-            }
-        }
+    /// so the value is converted to the default unit before storing.
+    fn try_set(&mut self, value: f64, unit: &Self::Unit) -> Result<(), UnitError> {
+        // convert the value to the default unit and store it
+        self.value = unit.unit_enum().try_convert_delta_to(value, &Self::unit_default())?;
+        Ok(())
+    }
+
+    /// Create a new Quantity with the given value and unit.
+    /// The value is converted to the default unit before storing.
+    fn try_new(value: f64, unit: &Self::Unit) -> Result<Self, UnitError> {
+        let value = unit.unit_enum().try_convert_delta_to(value, &Self::unit_default())?;
+        Ok(Quantity { value })
     }
-    
 }
 
-// ---------------------------------------------------------
+/// Implementing Clone trait for Quantity
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> Clone
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
-/// TemperatureValue struct
-pub struct TemperatureValue {
-    /// value of temperature. 
-    /// stored in Kelvin by default.
-    value:f64,
+/// Implementing Copy trait for Quantity
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> Copy
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
 }
 
-/// Implementing Value trait for TemperatureValue
-impl Value for TemperatureValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Kelvin,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> TemperatureValue {
-        // use the conversion function of the Unit
-        TemperatureValue { 
-            value: match unit {
-                UnitEnum::Temperature(temp_unit) => temp_unit.convert(
-                    self.value,
-                    &TemperatureUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for TemperatureValue"),
-            }
-        }
+/// Implementing PartialEq trait for Quantity
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> PartialEq
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
     }
+}
 
-    /// set self to the given value in the given unit
-    /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Kelvin before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Kelvin and store it
-        self.value = match unit {
-            UnitEnum::Temperature(temp_unit) => temp_unit.convert(
-                value,
-                unit,
-                &TemperatureUnit::default()
-            ),
-            _ => panic!("Invalid unit for TemperatureValue"),
-        };
-    }
-
-    /// Create a new TemperatureValue with the given value and unit.
-    /// The value is stored in Kelvin by default.
-    /// The value is converted to Kelvin before storing.
-    /// 
-    /// # Example
-    /// ```rust
-    /// let temperature = TemperatureValue::new(100.0, &UnitEnum::Temperature(TemperatureUnit::Kelvin));
-    /// assert_eq!(temperature.get(&UnitEnum::Temperature(TemperatureUnit::Kelvin)).value, 100.0);
-    /// ```
-    fn new(value: f64, unit: &UnitEnum) -> TemperatureValue {
-        TemperatureValue {
-            value: match unit {
-                UnitEnum::Temperature(temp_unit) => temp_unit.convert(
-                    value,
-                    unit,
-                    &TemperatureUnit::default()
-                ),
-                _ => panic!("Invalid unit for TemperatureValue"),
-            }
-        }
+/// Implementing PartialOrd trait for Quantity
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> PartialOrd
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
     }
+}
 
+/// Implementing Debug trait for Quantity
+/// Example: `Quantity { value: 1.0, dimension: [1, 0, -1, 0, 0, 0, 0, 0] }`
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::fmt::Debug
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Quantity")
+            .field("value", &self.value)
+            .field("dimension", &Self::EXPONENTS)
+            .finish()
+    }
+}
+
+/// Implementing Display trait for every Quantity that has a unit enum
+/// Writes the value in the default unit with the options of `ValueFormat::from_formatter`.
+/// Example: `format!("{speed:.1}")` -> "10.0 m/s", `format!("{speed:#}")` -> "10 Meters Per Second"
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::fmt::Display
+    for Quantity<L, M, T, TH, I, N, J, A>
+where
+    Self: MeasuredIn,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&ValueFormat::from_formatter(f).format_in(self.value, &Self::unit_default()))
+    }
 }
 
 // ---------------------------------------------------------
 
-/// VelocityValue struct
-pub struct VelocityValue {
-    /// value of velocity, stored in Knots
-    value:f64,
-
-}
-
-/// Implementing Value trait for VelocityValue
-impl Value for VelocityValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Meters Per Second,
-    /// so the value is converted from Meters Per Second to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> Self {
-        VelocityValue {
-            value: match unit {
-                UnitEnum::Velocity(velocity_unit) => velocity_unit.convert(
-                    self.value,
-                    &VelocityUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for VelocityValue"),
-            }
-        }
+/// Adding two Quantities of the same dimension
+/// Quantities of different dimensions cannot be added:
+///
+/// ```compile_fail
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let length = LengthValue::new(1.0, &LengthUnit::Meters);
+/// let time = TimeValue::new(1.0, &TimeUnit::Seconds);
+/// let nonsense = length + time;
+/// ```
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::Add
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Quantity { value: self.value + rhs.value }
     }
+}
 
-    /// Set self to the given value in the given unit.
-    /// Behind the scenes, the value is stored in Meters Per Second,
-    /// so the value is converted to Meters Per Second before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        self.value = match unit {
-            UnitEnum::Velocity(velocity_unit) => velocity_unit.convert(
-                value,
-                unit,
-                &VelocityUnit::default()
-            ),
-            _ => panic!("Invalid unit for VelocityValue"),
-        };
-    }
-
-    /// Create a new VelocityValue with the given value and unit.
-    /// The value is stored in Meters Per Second by default.
-    /// The value is converted to Meters Per Second before storing.
-    /// 
-    /// # Example
-    /// ```rust
-    /// let velocity = VelocityValue::new(100.0, &UnitEnum::Velocity(VelocityUnit::MetersPerSecond));
-    /// assert_eq!(velocity.get(&UnitEnum::Velocity(VelocityUnit::MetersPerSecond)).value, 100.0);
-    /// ```
-    fn new(value: f64, unit: &UnitEnum) -> Self {
-        VelocityValue {
-            value: match unit {
-                UnitEnum::Velocity(velocity_unit) => velocity_unit.convert(
-                    value,
-                    unit,
-                    &VelocityUnit::default()
-                ),
-                _ => panic!("Invalid unit for VelocityValue"),
-            }
-        }
+/// Subtracting two Quantities of the same dimension
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::Sub
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Quantity { value: self.value - rhs.value }
     }
 }
 
-// ---------------------------------------------------------
+/// Negating a Quantity
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::Neg
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    type Output = Self;
 
-/// ForceValue struct
-/// 
-/// The value is stored in Newtons by default.
-pub struct ForceValue {
-    /// value of force
-    value:f64,
+    fn neg(self) -> Self {
+        Quantity { value: -self.value }
+    }
 }
 
-/// Implementing Value trait for ForceValue
-impl Value for ForceValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Newtons,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> ForceValue {
-        // use the conversion function of the Unit
-        ForceValue { 
-            value: match unit {
-                UnitEnum::Force(force_unit) => force_unit.convert(
-                    self.value,
-                    &ForceUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for ForceValue"),
-            }
-        }
+/// Adding a Quantity of the same dimension in place
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::AddAssign
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
     }
+}
 
-    /// set self to the given value in the given unit
-    /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Newtons before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Newtons and store it
-        self.value = match unit {
-            UnitEnum::Force(force_unit) => force_unit.convert(
-                value,
-                unit,
-                &ForceUnit::default()
-            ),
-            _ => panic!("Invalid unit for ForceValue"),
-        };
-    }
-
-    /// Create a new ForceValue with the given value and unit.
-    /// The value is stored in Newtons by default.
-    /// The value is converted to Newtons before storing.
-    /// 
-    /// # Example
-    /// ```rust
-    /// let force = ForceValue::new(100.0, &UnitEnum::Force(ForceUnit::Newtons));
-    /// assert_eq!(force.get(&UnitEnum::Force(ForceUnit::Newtons)).value, 100.0);
-    /// ```
-    fn new(value: f64, unit: &UnitEnum) -> ForceValue {
-        ForceValue {
-            value: match unit {
-                UnitEnum::Force(force_unit) => force_unit.convert(
-                    value,
-                    unit,
-                    &ForceUnit::default()
-                ),
-                _ => panic!("Invalid unit for ForceValue"),
-            }
-        }
+/// Subtracting a Quantity of the same dimension in place
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::SubAssign
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+/// Scaling a Quantity by a number
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::Mul<f64>
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Quantity { value: self.value * rhs }
+    }
+}
+
+/// Scaling a Quantity by a number, with the number first
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::Mul<Quantity<L, M, T, TH, I, N, J, A>>
+    for f64
+{
+    type Output = Quantity<L, M, T, TH, I, N, J, A>;
+
+    fn mul(self, rhs: Quantity<L, M, T, TH, I, N, J, A>) -> Quantity<L, M, T, TH, I, N, J, A> {
+        Quantity { value: self * rhs.value }
+    }
+}
+
+/// Dividing a Quantity by a number
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::Div<f64>
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Quantity { value: self.value / rhs }
     }
+}
 
+/// Scaling a Quantity by a number in place
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::MulAssign<f64>
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn mul_assign(&mut self, rhs: f64) {
+        self.value *= rhs;
+    }
 }
 
+/// Dividing a Quantity by a number in place
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8> std::ops::DivAssign<f64>
+    for Quantity<L, M, T, TH, I, N, J, A>
+{
+    fn div_assign(&mut self, rhs: f64) {
+        self.value /= rhs;
+    }
+}
 
 // ---------------------------------------------------------
 
-/// PressureValue struct
-pub struct PressureValue {
-    /// value of pressure, stored in Pascals
-    value:f64,
+/// Add two sets of dimension exponents, as happens when multiplying Quantities.
+const fn add_exponents(lhs: [i8; 8], rhs: [i8; 8]) -> [i8; 8] {
+    let mut sum = [0; 8];
+    let mut i = 0;
+    while i < 8 {
+        sum[i] = lhs[i] + rhs[i];
+        i += 1;
+    }
+    sum
 }
 
-/// Implementing Value trait for PressureValue
-/// 
-/// The value is stored in Pascals by default.
-impl Value for PressureValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Pascals,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> PressureValue {
-        // use the conversion function of the Unit
-        PressureValue { 
-            value: match unit {
-                UnitEnum::Pressure(pressure_unit) => pressure_unit.convert(
-                    self.value,
-                    &PressureUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for PressureValue"),
-            }
+/// Compare two sets of dimension exponents.
+const fn same_exponents(lhs: [i8; 8], rhs: [i8; 8]) -> bool {
+    let mut i = 0;
+    while i < 8 {
+        if lhs[i] != rhs[i] {
+            return false;
         }
+        i += 1;
     }
+    true
+}
 
-    /// set self to the given value in the given unit
-    /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Pascals before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Pascals and store it
-        self.value = match unit {
-            UnitEnum::Pressure(pressure_unit) => pressure_unit.convert(
-                value,
-                unit,
-                &PressureUnit::default()
-            ),
-            _ => panic!("Invalid unit for PressureValue"),
-        };
-    }
-
-    /// Create a new PressureValue with the given value and unit.
-    /// The value is stored in Pascals by default.
-    /// The value is converted to Pascals before storing.
-    /// 
-    /// # Example
-    /// ```rust
-    /// let pressure = PressureValue::new(100.0, &UnitEnum::Pressure(PressureUnit::Pascals));
-    /// assert_eq!(pressure.get(&UnitEnum::Pressure(PressureUnit::Pascals)).value, 100.0);
-    /// ```
-    fn new(value: f64, unit: &UnitEnum) -> PressureValue {
-        PressureValue {
-            value: match unit {
-                UnitEnum::Pressure(pressure_unit) => pressure_unit.convert(
-                    value,
-                    unit,
-                    &PressureUnit::default()
-                ),
-                _ => panic!("Invalid unit for PressureValue"),
+/// Declares that `$lhs * $rhs` is `$out`.
+///
+/// Stable Rust cannot evaluate `{ L1 + L2 }` in a type position, so the
+/// products between Quantities are declared here rather than derived for
+/// every pair. Each declaration also generates the matching quotients
+/// (`$out / $rhs = $lhs` and `$out / $lhs = $rhs`), and is checked at
+/// compile time: a declaration whose exponents do not add up fails to build.
+macro_rules! quantity_product {
+    ($lhs:ty, $rhs:ty => $out:ty) => {
+        quantity_product!(@one $lhs, $rhs => $out);
+        quantity_product!(@one $rhs, $lhs => $out);
+    };
+    (square $lhs:ty => $out:ty) => {
+        quantity_product!(@one $lhs, $lhs => $out);
+    };
+    (@one $lhs:ty, $rhs:ty => $out:ty) => {
+        const _: () = assert!(same_exponents(
+            add_exponents(<$lhs>::EXPONENTS, <$rhs>::EXPONENTS),
+            <$out>::EXPONENTS
+        ));
+
+        impl std::ops::Mul<$rhs> for $lhs {
+            type Output = $out;
+
+            fn mul(self, rhs: $rhs) -> $out {
+                <$out>::from_si_value(self.value * rhs.value)
             }
         }
-    }
 
+        impl std::ops::Div<$rhs> for $out {
+            type Output = $lhs;
+
+            fn div(self, rhs: $rhs) -> $lhs {
+                <$lhs>::from_si_value(self.value / rhs.value)
+            }
+        }
+    };
 }
 
 // ---------------------------------------------------------
 
-/// BearingValue struct
-/// 
-/// The value is stored in Radians by default.
-pub struct BearingValue {
-    /// value of bearing
-    value:f64,
+/// LengthValue, stored in Meters
+pub type LengthValue = Quantity<1, 0, 0, 0, 0, 0, 0, 0>;
+
+/// MassValue, stored in Kilograms
+pub type MassValue = Quantity<0, 1, 0, 0, 0, 0, 0, 0>;
+
+/// TimeValue, stored in Seconds
+pub type TimeValue = Quantity<0, 0, 1, 0, 0, 0, 0, 0>;
+
+/// TemperatureDelta, a change of temperature, stored in Kelvin
+/// See `TemperatureValue` for absolute temperatures.
+pub type TemperatureDelta = Quantity<0, 0, 0, 1, 0, 0, 0, 0>;
+
+/// VelocityValue, stored in Meters Per Second
+pub type VelocityValue = Quantity<1, 0, -1, 0, 0, 0, 0, 0>;
+
+/// ForceValue, stored in Newtons
+pub type ForceValue = Quantity<1, 1, -2, 0, 0, 0, 0, 0>;
+
+/// PressureValue, stored in Pascals
+pub type PressureValue = Quantity<-1, 1, -2, 0, 0, 0, 0, 0>;
+
+/// AngleValue, an amount of rotation, stored in Radians
+/// See `bearing::BearingValue` for directions on the compass.
+pub type AngleValue = Quantity<0, 0, 0, 0, 0, 0, 0, 1>;
+
+/// AccelerationValue, stored in Meters Per Second Squared
+pub type AccelerationValue = Quantity<1, 0, -2, 0, 0, 0, 0, 0>;
+
+/// AreaValue, stored in Square Meters
+/// There is no unit enum for Area yet, so it is only readable in Square Meters.
+pub type AreaValue = Quantity<2, 0, 0, 0, 0, 0, 0, 0>;
+
+measured_in!(LengthUnit => LengthValue);
+measured_in!(MassUnit => MassValue);
+measured_in!(TimeUnit => TimeValue);
+measured_in!(VelocityUnit => VelocityValue);
+measured_in!(ForceUnit => ForceValue);
+measured_in!(PressureUnit => PressureValue);
+measured_in!(AccelerationUnit => AccelerationValue);
+
+// A TemperatureDelta is measured in TemperatureUnits, but the unit enum
+// measures absolute TemperatureValues, so the link only goes one way.
+impl MeasuredIn for TemperatureDelta {
+    type Unit = TemperatureUnit;
 }
 
-/// Implementing Value trait for BearingValue
-/// 
-/// The value is stored in Radians by default.
-impl Value for BearingValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Radians,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> BearingValue {
-        // use the conversion function of the Unit
-        BearingValue { 
-            value: match unit {
-                UnitEnum::Bearing(bearing_unit) => bearing_unit.convert(
-                    self.value,
-                    &BearingUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for BearingValue"),
-            }
-        }
+impl Measures for TemperatureUnit {
+    type Quantity = TemperatureValue;
+}
+
+// Likewise an AngleValue is measured in BearingUnits,
+// but the unit enum measures BearingValues, which wrap around north.
+impl MeasuredIn for AngleValue {
+    type Unit = BearingUnit;
+}
+
+impl Measures for BearingUnit {
+    type Quantity = BearingValue;
+}
+
+// Velocity = Length / Time
+quantity_product!(VelocityValue, TimeValue => LengthValue);
+// Acceleration = Velocity / Time
+quantity_product!(AccelerationValue, TimeValue => VelocityValue);
+// Force = Mass * Acceleration
+quantity_product!(MassValue, AccelerationValue => ForceValue);
+// Area = Length * Length
+quantity_product!(square LengthValue => AreaValue);
+// Pressure = Force / Area
+quantity_product!(PressureValue, AreaValue => ForceValue);
+
+// ---------------------------------------------------------
+
+/// Absolute struct
+///
+/// A point on a scale with a zero, such as an absolute temperature,
+/// an absolute pressure, or an elevation above sea level,
+/// stored in the SI unit of the dimension of the Quantity `Q`.
+///
+/// Converting an Absolute value applies the offset of the unit,
+/// so 10 °C is 283.15 K and 0 psig is 101325 Pa, while converting the Quantity
+/// does not, so a change of 10 °C is a change of 10 K.
+///
+/// The arithmetic follows the rules:
+/// - Absolute - Absolute = Quantity
+/// - Absolute ± Quantity = Absolute
+///
+/// # Example
+/// ```rust
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let tank = AbsolutePressure::new(30.0, &PressureUnit::PoundsPerSquareInchGauge);
+/// let outside = AbsolutePressure::new(1.0, &PressureUnit::Atmospheres);
+/// let difference = tank - outside;
+/// assert!((difference.value_in(&PressureUnit::PoundsPerSquareInch) - 30.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Absolute<Q> {
+    /// value in the SI unit of the dimension, from the SI zero
+    value: f64,
+    /// the Quantity measuring differences between Absolute values
+    quantity: std::marker::PhantomData<Q>,
+}
+
+/// TemperatureValue, an absolute temperature, stored in Kelvin
+///
+/// Converting a TemperatureValue applies the offset of the temperature scale,
+/// while converting a `TemperatureDelta` does not.
+///
+/// The arithmetic follows the rules:
+/// - TemperatureValue - TemperatureValue = TemperatureDelta
+/// - TemperatureValue ± TemperatureDelta = TemperatureValue
+///
+/// # Example
+/// ```rust
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let morning = TemperatureValue::new(10.0, &TemperatureUnit::Celcius);
+/// let afternoon = TemperatureValue::new(25.0, &TemperatureUnit::Celcius);
+/// let rise = afternoon - morning;
+/// assert_eq!(rise.value_in(&TemperatureUnit::Kelvin), 15.0);
+/// assert_eq!((morning + rise).value_in(&TemperatureUnit::Celcius), 25.0);
+/// ```
+///
+/// Adding two absolute temperatures is meaningless and does not compile:
+///
+/// ```compile_fail
+/// use units_and_values::units::*;
+/// use units_and_values::values::*;
+///
+/// let morning = TemperatureValue::new(10.0, &TemperatureUnit::Celcius);
+/// let sum = morning + morning;
+/// ```
+pub type TemperatureValue = Absolute<TemperatureDelta>;
+
+/// AbsolutePressure, a pressure measured from vacuum, stored in Pascals
+/// Gauge units such as psig are measured from the atmosphere instead;
+/// see `Absolute::try_new_with_reference` for an atmosphere other than the standard one.
+pub type AbsolutePressure = Absolute<PressureValue>;
+
+/// Elevation, a height above sea level, stored in Meters
+/// The difference between two Elevations is a LengthValue,
+/// so the height above the ground is the elevation minus the ground's elevation.
+pub type Elevation = Absolute<LengthValue>;
+
+impl<Q: Value> Absolute<Q> {
+    /// Parse an absolute value written as a number followed by a unit.
+    /// See `parse::parse_quantity` for the accepted formats.
+    ///
+    /// Returns an error if the text does not parse,
+    /// if the unit measures a different dimension, or if the value is out of the unit's domain,
+    /// such as below absolute zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// let temperature = TemperatureValue::parse("-40 °F").unwrap();
+    /// assert!((temperature.value_in(&TemperatureUnit::Celcius) + 40.0).abs() < 1e-9);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, UnitError> {
+        let parsed = parse_quantity_as(Self::unit_default().dimension(), input)?;
+        Ok(Self::from_si_value(parsed.quantity().si_value()))
     }
 
-    /// set self to the given value in the given unit
-    /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Radians before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Radians and store it
-        self.value = match unit {
-            UnitEnum::Bearing(bearing_unit) => bearing_unit.convert(
-                value,
-                unit,
-                &BearingUnit::default()
-            ),
-            _ => panic!("Invalid unit for BearingValue"),
-        };
-    }
-
-    /// Create a new BearingValue with the given value and unit.
-    /// The value is stored in Radians by default.
-    /// The value is converted to Radians before storing.
-    /// 
+    /// Create a new Absolute value from a value in the given unit,
+    /// measuring a gauge unit from `reference` instead of its standard reference.
+    /// Units that are not gauge units ignore the reference.
+    ///
     /// # Example
     /// ```rust
-    /// let bearing = BearingValue::new(1.0, &UnitEnum::Bearing(BearingUnit::Radians));
-    /// assert_eq!(bearing.get(&UnitEnum::Bearing(BearingUnit::Radians)).value, 1.0);
+    /// use units_and_values::units::*;
+    /// use units_and_values::values::*;
+    ///
+    /// // a tyre pumped to 30 psig high in the mountains
+    /// let atmosphere = AbsolutePressure::new(70.0, &PressureUnit::Kilopascals);
+    /// let tyre = AbsolutePressure::try_new_with_reference(30.0, &PressureUnit::PoundsPerSquareInchGauge, atmosphere).unwrap();
+    /// assert!((tyre.value_in(&PressureUnit::PoundsPerSquareInchGauge) - 25.46).abs() < 0.01);
     /// ```
-    fn new(value: f64, unit: &UnitEnum) -> BearingValue {
-        BearingValue {
-            value: match unit {
-                UnitEnum::Bearing(bearing_unit) => bearing_unit.convert(
-                    value,
-                    unit,
-                    &BearingUnit::default()
-                ),
-                _ => panic!("Invalid unit for BearingValue"),
-            }
-        }
+    pub fn try_new_with_reference(value: f64, unit: &Q::Unit, reference: Absolute<Q>) -> Result<Self, UnitError> {
+        let value = unit.unit_enum().try_to_si_with_reference(value, reference.value)?;
+        Ok(Self::from_si_value(value))
     }
 
+    /// Get the value in the given unit,
+    /// measuring a gauge unit from `reference` instead of its standard reference.
+    /// Units that are not gauge units ignore the reference.
+    pub fn try_value_in_with_reference(&self, unit: &Q::Unit, reference: Absolute<Q>) -> Result<f64, UnitError> {
+        unit.unit_enum().try_from_si_with_reference(self.value, reference.value)
+    }
 }
 
-// ---------------------------------------------------------
+/// Implementing FromStr trait for Absolute
+/// Same as `Absolute::parse`.
+impl<Q: Value> std::str::FromStr for Absolute<Q> {
+    type Err = UnitError;
 
-/// AccelerationValue struct
-/// 
-/// The value is stored in Meters Per Second Squared by default.
-pub struct AccelerationValue {
-    /// value of acceleration
-    value:f64,
+    fn from_str(input: &str) -> Result<Self, UnitError> {
+        Self::parse(input)
+    }
 }
 
-/// Implementing Value trait for AccelerationValue
-/// 
-/// The value is stored in Meters Per Second Squared by default.
-impl Value for AccelerationValue {
-    /// Get the value in the given unit.
-    /// Behind the scenes, the value is stored in Meters Per Second Squared,
-    /// so the value is converted to the given unit before returning.
-    fn get(&self, unit: &UnitEnum) -> AccelerationValue {
-        // use the conversion function of the Unit
-        AccelerationValue { 
-            value: match unit {
-                UnitEnum::Acceleration(acceleration_unit) => acceleration_unit.convert(
-                    self.value,
-                    &AccelerationUnit::default(),
-                    unit
-                ),
-                _ => panic!("Invalid unit for AccelerationValue"),
-            }
-        }
+/// Implementing Value trait for Absolute
+impl<Q: Value> Value for Absolute<Q> {
+    type Unit = Q::Unit;
+
+    /// Create a new Absolute value from a value in the default unit.
+    fn from_si_value(value: f64) -> Self {
+        Absolute { value, quantity: std::marker::PhantomData }
     }
 
-    /// set self to the given value in the given unit
-    /// Behind the scenes, the value is stored in the default unit,
-    /// so the value is converted to Meters Per Second Squared before storing.
-    fn set(&mut self, value: f64, unit: &UnitEnum) {
-        // conver the value to Meters Per Second Squared and store it
-        self.value = match unit {
-            UnitEnum::Acceleration(acceleration_unit) => acceleration_unit.convert(
-                value,
-                unit,
-                &AccelerationUnit::default()
-            ),
-            _ => panic!("Invalid unit for AccelerationValue"),
-        };
-    }
-
-    /// Create a new AccelerationValue with the given value and unit.
-    /// The value is stored in Meters Per Second Squared by default.
-    /// The value is converted to Meters Per Second Squared before storing.
-    /// 
-    /// # Example
-    /// ```rust
-    /// let acceleration = AccelerationValue::new(100.0, &UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared));
-    /// assert_eq!(acceleration.get(&UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared)).value, 100.0);
-    /// ```
-    fn new(value: f64, unit: &UnitEnum) -> AccelerationValue {
-        AccelerationValue {
-            value: match unit {
-                UnitEnum::Acceleration(acceleration_unit) => acceleration_unit.convert(
-                    value,
-                    unit,
-                    &AccelerationUnit::default()
-                ),
-                _ => panic!("Invalid unit for AccelerationValue"),
-            }
-        }
+    /// Get the value in the default unit.
+    fn si_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the unit the value is stored in.
+    fn unit_default() -> UnitEnum {
+        Q::unit_default()
+    }
+
+    /// Get the value in the given unit, applying the offset of the unit.
+    fn try_value_in(&self, unit: &Q::Unit) -> Result<f64, UnitError> {
+        unit.try_convert(self.value, &Self::unit_default(), &unit.unit_enum())
+    }
+
+    /// Get the value in a unit only known at runtime, applying the offset of the unit.
+    fn try_value_in_unit(&self, unit: &UnitEnum) -> Result<f64, UnitError> {
+        Self::unit_default().try_convert_to(self.value, unit)
+    }
+
+    /// Set self to the given value in the given unit, applying the offset of the unit.
+    fn try_set(&mut self, value: f64, unit: &Q::Unit) -> Result<(), UnitError> {
+        self.value = unit.try_convert(value, &unit.unit_enum(), &Self::unit_default())?;
+        Ok(())
+    }
+
+    /// Create a new Absolute value from a value in the given unit.
+    ///
+    /// Returns an error if the value is not finite or out of the unit's domain.
+    fn try_new(value: f64, unit: &Q::Unit) -> Result<Self, UnitError> {
+        let value = unit.try_convert(value, &unit.unit_enum(), &Self::unit_default())?;
+        Ok(Self::from_si_value(value))
+    }
+}
+
+/// Implementing Display trait for Absolute
+/// Writes the value in the default unit, measured from its zero.
+/// Example: 20 °C -> "293.15 K"
+impl<Q: Value> std::fmt::Display for Absolute<Q> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&ValueFormat::from_formatter(f).format_in(self.value, &Self::unit_default()))
+    }
+}
+
+/// The difference between two Absolute values
+impl<Q: Value> std::ops::Sub for Absolute<Q> {
+    type Output = Q;
+
+    fn sub(self, rhs: Absolute<Q>) -> Q {
+        Q::from_si_value(self.value - rhs.value)
+    }
+}
+
+/// Moving an Absolute value up by a Quantity
+impl<Q: Value> std::ops::Add<Q> for Absolute<Q> {
+    type Output = Absolute<Q>;
+
+    fn add(self, rhs: Q) -> Absolute<Q> {
+        Absolute::from_si_value(self.value + rhs.si_value())
+    }
+}
+
+/// Moving an Absolute value up by a Quantity
+impl<const L: i8, const M: i8, const T: i8, const TH: i8, const I: i8, const N: i8, const J: i8, const A: i8>
+    std::ops::Add<Absolute<Quantity<L, M, T, TH, I, N, J, A>>> for Quantity<L, M, T, TH, I, N, J, A>
+where
+    Self: MeasuredIn,
+{
+    type Output = Absolute<Quantity<L, M, T, TH, I, N, J, A>>;
+
+    fn add(self, rhs: Absolute<Quantity<L, M, T, TH, I, N, J, A>>) -> Self::Output {
+        rhs + self
+    }
+}
+
+/// Moving an Absolute value down by a Quantity
+impl<Q: Value> std::ops::Sub<Q> for Absolute<Q> {
+    type Output = Absolute<Q>;
+
+    fn sub(self, rhs: Q) -> Absolute<Q> {
+        Absolute::from_si_value(self.value - rhs.si_value())
     }
+}
 
+/// Moving an Absolute value up in place
+impl<Q: Value> std::ops::AddAssign<Q> for Absolute<Q> {
+    fn add_assign(&mut self, rhs: Q) {
+        self.value += rhs.si_value();
+    }
 }
 
+/// Moving an Absolute value down in place
+impl<Q: Value> std::ops::SubAssign<Q> for Absolute<Q> {
+    fn sub_assign(&mut self, rhs: Q) {
+        self.value -= rhs.si_value();
+    }
+}
 
 // Tests
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::units::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_length_value() {
-        let length = LengthValue::new(100.0, &UnitEnum::Length(LengthUnit::Meters));
-        assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Meters)).value, 100.0);
-        assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Kilometers)).value, 0.1);
-        assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Feet)).value, 328.084);
-        assert_eq!(length.get(&UnitEnum::Length(LengthUnit::Inches)).value, 3937.007874015748);
+        let length = LengthValue::new(100.0, &LengthUnit::Meters);
+        assert_eq!(length.get(&LengthUnit::Meters).value, 100.0);
+        assert_eq!(length.get(&LengthUnit::Kilometers).value, 0.1);
+        assert_eq!(length.get(&LengthUnit::Feet).value, 328.0839895013123);
+        assert_eq!(length.get(&LengthUnit::Inches).value, 3937.0078740157483);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_mass_value() {
-        let mass = MassValue::new(100.0, &UnitEnum::Mass(MassUnit::Kilograms));
-        assert_eq!(mass.get(&UnitEnum::Mass(MassUnit::Kilograms)).value, 100.0);
-        assert_eq!(mass.get(&UnitEnum::Mass(MassUnit::Grams)).value, 100000.0);
-        assert_eq!(mass.get(&UnitEnum::Mass(MassUnit::PoundsMass)).value, 220.46226218488);
+        let mass = MassValue::new(100.0, &MassUnit::Kilograms);
+        assert_eq!(mass.get(&MassUnit::Kilograms).value, 100.0);
+        assert_eq!(mass.get(&MassUnit::Grams).value, 100000.0);
+        assert_eq!(mass.get(&MassUnit::PoundsMass).value, 220.46226218487757);
+    }
+
+    #[test]
+    fn test_try_value_errors() {
+        assert!(matches!(
+            LengthValue::try_new(f64::NAN, &LengthUnit::Meters),
+            Err(UnitError::NonFinite(_))
+        ));
+        assert!(matches!(
+            TemperatureValue::try_new(-500.0, &TemperatureUnit::Fehrenheit),
+            Err(UnitError::OutOfDomain { .. })
+        ));
+
+        let mut mass = MassValue::new(1.0, &MassUnit::Kilograms);
+        assert!(mass.try_set(f64::INFINITY, &MassUnit::Grams).is_err());
+        assert_eq!(mass.value_in(&MassUnit::Kilograms), 1.0);
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        assert!(matches!(
+            LengthUnit::Meters.try_from_str("ft"),
+            Ok(UnitEnum::Length(LengthUnit::Feet))
+        ));
+        assert_eq!(
+            LengthUnit::Meters.try_from_str("furlongs").err(),
+            Some(UnitError::UnknownUnit(String::from("furlongs")))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "is not finite")]
+    fn test_new_panics_on_non_finite() {
+        LengthValue::new(f64::INFINITY, &LengthUnit::Meters);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_measurement_aliases() {
+        let speed = VelocityValue::new(10.0, &VelocityUnit::MetersPerSecond);
+        assert_eq!(speed.si_value(), 10.0);
+        assert_eq!(VelocityValue::unit_default(), UnitEnum::Velocity(VelocityUnit::MetersPerSecond));
+        assert!(LengthValue::new(1.0, &LengthUnit::Feet) < LengthValue::new(1.0, &LengthUnit::Meters));
+    }
+
+    #[test]
+    fn test_quantity_products() {
+        let distance = LengthValue::new(100.0, &LengthUnit::Meters);
+        let time = TimeValue::new(20.0, &TimeUnit::Seconds);
+        let speed: VelocityValue = distance / time;
+        assert_eq!(speed.si_value(), 5.0);
+        assert_eq!((speed * time).si_value(), 100.0);
+
+        let acceleration: AccelerationValue = speed / time;
+        assert_eq!(acceleration.si_value(), 0.25);
+
+        let force: ForceValue = MassValue::new(4.0, &MassUnit::Kilograms) * acceleration;
+        assert_eq!(force.value_in(&ForceUnit::Newtons), 1.0);
+        assert_eq!(ForceValue::EXPONENTS, [1, 1, -2, 0, 0, 0, 0, 0]);
+
+        let length: Measurement<LengthUnit> = distance;
+        assert_eq!(length, distance);
+    }
+
+    #[test]
+    fn test_quantity_arithmetic() {
+        let a = LengthValue::new(1.0, &LengthUnit::Kilometers);
+        let b = LengthValue::new(500.0, &LengthUnit::Meters);
+        assert_eq!((a + b).si_value(), 1500.0);
+        assert_eq!((a - b).si_value(), 500.0);
+        assert_eq!((-a).si_value(), -1000.0);
+        assert_eq!((a * 2.0).si_value(), 2000.0);
+        assert_eq!((2.0 * a).si_value(), 2000.0);
+        assert_eq!((a / 4.0).si_value(), 250.0);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 3.0;
+        c /= 1.5;
+        assert_eq!(c.si_value(), 1000.0);
+
+        let area: AreaValue = a * b;
+        assert_eq!(area.si_value(), 500000.0);
+        assert_eq!((area / a).si_value(), 500.0);
+
+        let force = ForceValue::new(1000.0, &ForceUnit::Newtons);
+        let pressure: PressureValue = force / AreaValue::from_si_value(2.0);
+        assert_eq!(pressure.value_in(&PressureUnit::Kilopascals), 0.5);
+    }
+
+    #[test]
+    fn test_temperature_delta() {
+        let change = TemperatureDelta::new(10.0, &TemperatureUnit::Celcius);
+        assert_eq!(change.value_in(&TemperatureUnit::Kelvin), 10.0);
+        assert_eq!(change.value_in(&TemperatureUnit::Fehrenheit), 18.0);
+        // a change of temperature is not limited by absolute zero
+        let drop = TemperatureDelta::new(-500.0, &TemperatureUnit::Fehrenheit);
+        assert!((drop.value_in(&TemperatureUnit::Rankine) + 500.0).abs() < 1e-9);
+        assert_eq!("10 °C".parse::<TemperatureDelta>(), Ok(change));
+
+        let absolute = TemperatureValue::new(10.0, &TemperatureUnit::Celcius);
+        assert_eq!(absolute.value_in(&TemperatureUnit::Kelvin), 283.15);
+        assert_eq!("10 °C".parse::<TemperatureValue>(), Ok(absolute));
+    }
+
+    #[test]
+    fn test_temperature_arithmetic() {
+        let freezing = TemperatureValue::new(32.0, &TemperatureUnit::Fehrenheit);
+        let boiling = TemperatureValue::new(212.0, &TemperatureUnit::Fehrenheit);
+        let range = boiling - freezing;
+        assert!((range.value_in(&TemperatureUnit::Celcius) - 100.0).abs() < 1e-9);
+
+        let mut temperature = freezing + TemperatureDelta::new(20.0, &TemperatureUnit::Kelvin);
+        assert!((temperature.value_in(&TemperatureUnit::Celcius) - 20.0).abs() < 1e-9);
+        temperature -= TemperatureDelta::new(5.0, &TemperatureUnit::Kelvin);
+        assert_eq!(temperature, freezing + TemperatureDelta::new(15.0, &TemperatureUnit::Kelvin));
+        assert!(boiling - range == freezing);
+    }
+
+    #[test]
+    fn test_gauge_pressure() {
+        let atmosphere = AbsolutePressure::new(0.0, &PressureUnit::PoundsPerSquareInchGauge);
+        assert!((atmosphere.value_in(&PressureUnit::Pascals) - 101325.0).abs() < 1e-9);
+        let tank = AbsolutePressure::new(2.0, &PressureUnit::BarsGauge);
+        assert!((tank.value_in(&PressureUnit::Bars) - 3.01325).abs() < 1e-12);
+        assert!(((tank - atmosphere).value_in(&PressureUnit::Bars) - 2.0).abs() < 1e-12);
+        // an amount of pressure has no zero, so the gauge offset does not apply
+        assert_eq!(PressureValue::new(2.0, &PressureUnit::BarsGauge).value_in(&PressureUnit::Bars), 2.0);
+
+        let thin_air = AbsolutePressure::new(80000.0, &PressureUnit::Pascals);
+        let local = AbsolutePressure::try_new_with_reference(2.0, &PressureUnit::BarsGauge, thin_air).unwrap();
+        assert_eq!(local.value_in(&PressureUnit::Pascals), 280000.0);
+        let gauge = local.try_value_in_with_reference(&PressureUnit::BarsGauge, thin_air).unwrap();
+        assert!((gauge - 2.0).abs() < 1e-12);
+        // only gauge units use the reference
+        assert_eq!(local.try_value_in_with_reference(&PressureUnit::Bars, thin_air), Ok(2.8));
+        assert_eq!("2 barg".parse::<AbsolutePressure>(), Ok(tank));
+    }
+
+    #[test]
+    fn test_elevation() {
+        let summit = Elevation::new(8849.0, &LengthUnit::Meters);
+        let base_camp = Elevation::new(17598.0, &LengthUnit::Feet);
+        let climb: LengthValue = summit - base_camp;
+        assert!((climb.value_in(&LengthUnit::Meters) - 3485.1296).abs() < 1e-9);
+        assert_eq!(base_camp + climb, summit);
+        assert_eq!(climb + base_camp, summit);
+    }
+
+    #[test]
+    fn test_round_to() {
+        let speed = VelocityValue::new(12.6, &VelocityUnit::Knots);
+        assert_eq!(speed.round_to(5.0, &VelocityUnit::Knots).value_in(&VelocityUnit::Knots), 15.0);
+        let altitude = Elevation::new(1234.0, &LengthUnit::Feet);
+        assert_eq!(altitude.round_to(10.0, &LengthUnit::Feet).value_in(&LengthUnit::Feet), 1230.0);
+        let cut = LengthValue::new(3.6, &LengthUnit::Inches);
+        assert!((cut.round_to(1.0 / 16.0, &LengthUnit::Inches).value_in(&LengthUnit::Inches) - 3.625).abs() < 1e-12);
+        assert!(matches!(speed.try_round_to(0.0, &VelocityUnit::Knots), Err(UnitError::OutOfDomain { .. })));
+        assert!(speed.try_round_to(f64::NAN, &VelocityUnit::Knots).is_err());
+    }
+
+    #[test]
+    fn test_parse_value() {
+        let pressure = PressureValue::parse("1013.25 hPa").unwrap();
+        assert_eq!(pressure.value_in(&PressureUnit::Atmospheres), 1.0);
+        assert_eq!("2 min".parse::<TimeValue>(), Ok(TimeValue::from_si_value(120.0)));
+        assert_eq!(MassValue::parse("3 g"), Ok(MassValue::from_si_value(0.003)));
+        assert!(AccelerationValue::parse("3 g").is_ok());
+        assert_eq!(
+            LengthValue::parse("3 kg"),
+            Err(UnitError::DimensionMismatch { expected: Dimension::LENGTH, found: Dimension::MASS })
+        );
+    }
+}
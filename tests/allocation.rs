@@ -0,0 +1,80 @@
+//! Checks that unit conversions never allocate.
+//!
+//! A counting global allocator records the allocations made on each thread,
+//! so tests running in parallel do not see each other's allocations.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use units_and_values::units::*;
+use units_and_values::values::*;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// System allocator that counts allocations per thread
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // the thread local may already be gone while a thread shuts down
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Count the allocations made on this thread while running `f`.
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+/// A telemetry-sized batch of readings.
+fn readings() -> Vec<f64> {
+    (0..10_000).map(|i| f64::from(i) * 0.37).collect()
+}
+
+#[test]
+fn test_length_value_in_does_not_allocate() {
+    let units = [
+        LengthUnit::Meters,
+        LengthUnit::Kilometers,
+        LengthUnit::Feet,
+        LengthUnit::Miles,
+        LengthUnit::NauticalMiles,
+    ];
+    let lengths: Vec<LengthValue> = readings().iter().map(|value| LengthValue::new(*value, &LengthUnit::Meters)).collect();
+    let mut total = 0.0;
+    let count = allocations_during(|| {
+        for length in &lengths {
+            for unit in &units {
+                total += length.value_in(unit);
+            }
+        }
+    });
+    assert_eq!(count, 0);
+    assert!(total > 0.0);
+}
+
+#[test]
+fn test_bulk_conversion_does_not_allocate() {
+    let mut values = readings();
+    let count = allocations_during(|| {
+        for unit in UnitEnum::all() {
+            for value in values.iter_mut() {
+                // stay above absolute zero for the temperature units
+                *value = unit.try_from_si(unit.try_to_si(value.abs()).unwrap()).unwrap();
+            }
+        }
+    });
+    assert_eq!(count, 0);
+}
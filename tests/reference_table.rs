@@ -0,0 +1,288 @@
+//! Checks every pair of units in every dimension against reference values.
+//!
+//! The linear units are checked against the size of one unit in SI, as listed
+//! in NIST SP 811 Appendix B. Exact definitions carry a tolerance of a few ulps;
+//! values NIST rounds to seven significant digits carry a tolerance of 1e-6.
+//! SP 811 does not list the artillery mils, which are defined as a fraction
+//! of a full turn (6400, 6000 or 6300 mils), so they are computed from 2π.
+//! The temperature scales and the gauge pressure units have an offset,
+//! so they are checked against fixed points instead.
+//!
+//! Round trips are then checked over randomised magnitudes.
+
+use units_and_values::units::*;
+
+/// Relative tolerance for factors that NIST defines exactly
+const EXACT: f64 = 4.0 * f64::EPSILON;
+/// Relative tolerance for factors that NIST rounds to seven significant digits
+const NIST_ROUNDED: f64 = 1e-6;
+
+/// A full turn, in Radians
+const TURN: f64 = std::f64::consts::TAU;
+
+/// Size of one of each linear unit in the SI unit of its dimension,
+/// with the relative tolerance of the reference value.
+const REFERENCE: &[(UnitEnum, f64, f64)] = &[
+    // Length, in Meters
+    (UnitEnum::Length(LengthUnit::Meters), 1.0, EXACT),
+    (UnitEnum::Length(LengthUnit::Kilometers), 1000.0, EXACT),
+    (UnitEnum::Length(LengthUnit::Centimeters), 0.01, EXACT),
+    (UnitEnum::Length(LengthUnit::Millimeters), 0.001, EXACT),
+    (UnitEnum::Length(LengthUnit::Inches), 0.0254, EXACT),
+    (UnitEnum::Length(LengthUnit::Feet), 0.3048, EXACT),
+    (UnitEnum::Length(LengthUnit::Yards), 0.9144, EXACT),
+    (UnitEnum::Length(LengthUnit::Miles), 1609.344, EXACT),
+    (UnitEnum::Length(LengthUnit::NauticalMiles), 1852.0, EXACT),
+    (UnitEnum::Length(LengthUnit::Mils), 2.54e-5, EXACT),
+    // Mass, in Kilograms
+    (UnitEnum::Mass(MassUnit::Kilograms), 1.0, EXACT),
+    (UnitEnum::Mass(MassUnit::Grams), 1e-3, EXACT),
+    (UnitEnum::Mass(MassUnit::Milligrams), 1e-6, EXACT),
+    (UnitEnum::Mass(MassUnit::PoundsMass), 0.45359237, EXACT),
+    (UnitEnum::Mass(MassUnit::Ounces), 0.028349523125, EXACT),
+    (UnitEnum::Mass(MassUnit::Slugs), 14.59390, NIST_ROUNDED),
+    // Time, in Seconds
+    (UnitEnum::Time(TimeUnit::Seconds), 1.0, EXACT),
+    (UnitEnum::Time(TimeUnit::Milliseconds), 1e-3, EXACT),
+    (UnitEnum::Time(TimeUnit::Microseconds), 1e-6, EXACT),
+    (UnitEnum::Time(TimeUnit::Nanoseconds), 1e-9, EXACT),
+    (UnitEnum::Time(TimeUnit::Minutes), 60.0, EXACT),
+    (UnitEnum::Time(TimeUnit::Hours), 3600.0, EXACT),
+    (UnitEnum::Time(TimeUnit::Days), 86400.0, EXACT),
+    (UnitEnum::Time(TimeUnit::Weeks), 604800.0, EXACT),
+    // Velocity, in Meters Per Second
+    (UnitEnum::Velocity(VelocityUnit::MetersPerSecond), 1.0, EXACT),
+    (UnitEnum::Velocity(VelocityUnit::FeetPerSecond), 0.3048, EXACT),
+    (UnitEnum::Velocity(VelocityUnit::KilometersPerHour), 0.2777778, NIST_ROUNDED),
+    (UnitEnum::Velocity(VelocityUnit::MilesPerHour), 0.44704, EXACT),
+    (UnitEnum::Velocity(VelocityUnit::Knots), 0.5144444, NIST_ROUNDED),
+    // Force, in Newtons
+    (UnitEnum::Force(ForceUnit::Newtons), 1.0, EXACT),
+    (UnitEnum::Force(ForceUnit::PoundsForce), 4.4482216152605, EXACT),
+    (UnitEnum::Force(ForceUnit::KilogramsForce), 9.80665, EXACT),
+    // Pressure, in Pascals
+    (UnitEnum::Pressure(PressureUnit::Pascals), 1.0, EXACT),
+    (UnitEnum::Pressure(PressureUnit::Kilopascals), 1e3, EXACT),
+    (UnitEnum::Pressure(PressureUnit::Megapascals), 1e6, EXACT),
+    (UnitEnum::Pressure(PressureUnit::Hectopascals), 100.0, EXACT),
+    (UnitEnum::Pressure(PressureUnit::InchesOfMercury), 3386.389, NIST_ROUNDED),
+    (UnitEnum::Pressure(PressureUnit::MillimetersOfMercury), 133.3224, NIST_ROUNDED),
+    (UnitEnum::Pressure(PressureUnit::Milibars), 100.0, EXACT),
+    (UnitEnum::Pressure(PressureUnit::Atmospheres), 101325.0, EXACT),
+    (UnitEnum::Pressure(PressureUnit::PoundsPerSquareInch), 6894.757, NIST_ROUNDED),
+    (UnitEnum::Pressure(PressureUnit::Bars), 1e5, EXACT),
+    // Angle, in Radians
+    (UnitEnum::Bearing(BearingUnit::Radians), 1.0, EXACT),
+    (UnitEnum::Bearing(BearingUnit::Degrees), 1.745329e-2, NIST_ROUNDED),
+    (UnitEnum::Bearing(BearingUnit::ArcMinutes), 2.908882e-4, NIST_ROUNDED),
+    (UnitEnum::Bearing(BearingUnit::ArcSeconds), 4.848137e-6, NIST_ROUNDED),
+    (UnitEnum::Bearing(BearingUnit::Gradians), 1.570796e-2, NIST_ROUNDED),
+    (UnitEnum::Bearing(BearingUnit::Milliradians), 1e-3, EXACT),
+    (UnitEnum::Bearing(BearingUnit::NatoMils), TURN / 6400.0, EXACT),
+    (UnitEnum::Bearing(BearingUnit::WarsawPactMils), TURN / 6000.0, EXACT),
+    (UnitEnum::Bearing(BearingUnit::SwedishMils), TURN / 6300.0, EXACT),
+    // Acceleration, in Meters Per Second Squared
+    (UnitEnum::Acceleration(AccelerationUnit::MetersPerSecondSquared), 1.0, EXACT),
+    (UnitEnum::Acceleration(AccelerationUnit::FeetPerSecondSquared), 0.3048, EXACT),
+    (UnitEnum::Acceleration(AccelerationUnit::KilometersPerSecondSquared), 1000.0, EXACT),
+    (UnitEnum::Acceleration(AccelerationUnit::MilesPerHourPerSecond), 0.44704, EXACT),
+    (UnitEnum::Acceleration(AccelerationUnit::StandardGravity), 9.80665, EXACT),
+];
+
+/// Temperature scales, in the order of the columns of TEMPERATURE_POINTS
+const TEMPERATURE_UNITS: [UnitEnum; 4] = [
+    UnitEnum::Temperature(TemperatureUnit::Kelvin),
+    UnitEnum::Temperature(TemperatureUnit::Celcius),
+    UnitEnum::Temperature(TemperatureUnit::Fehrenheit),
+    UnitEnum::Temperature(TemperatureUnit::Rankine),
+];
+
+/// The same temperatures on each scale
+const TEMPERATURE_POINTS: &[[f64; 4]] = &[
+    // absolute zero
+    [0.0, -273.15, -459.67, 0.0],
+    // the scales of Celcius and Fehrenheit cross
+    [233.15, -40.0, -40.0, 419.67],
+    // freezing point of water
+    [273.15, 0.0, 32.0, 491.67],
+    // body temperature
+    [310.15, 37.0, 98.6, 558.27],
+    // boiling point of water
+    [373.15, 100.0, 212.0, 671.67],
+];
+
+/// Absolute tolerance of the temperature points, in degrees
+const TEMPERATURE_TOLERANCE: f64 = 1e-9;
+
+/// Pressure units with and without a gauge offset, in the order of the columns of PRESSURE_POINTS
+const PRESSURE_UNITS: [UnitEnum; 5] = [
+    UnitEnum::Pressure(PressureUnit::Pascals),
+    UnitEnum::Pressure(PressureUnit::PoundsPerSquareInch),
+    UnitEnum::Pressure(PressureUnit::PoundsPerSquareInchGauge),
+    UnitEnum::Pressure(PressureUnit::Bars),
+    UnitEnum::Pressure(PressureUnit::BarsGauge),
+];
+
+/// The same pressures in each unit
+const PRESSURE_POINTS: &[[f64; 5]] = &[
+    // vacuum
+    [0.0, 0.0, -14.695948775513449, 0.0, -1.01325],
+    // standard atmosphere
+    [101325.0, 14.695948775513449, 0.0, 1.01325, 0.0],
+    // 2 bar gauge
+    [301325.0, 43.70349632155529, 29.00754754604184, 3.01325, 2.0],
+];
+
+/// Absolute tolerance of the pressure points, in the unit
+const PRESSURE_TOLERANCE: f64 = 1e-9;
+
+/// Check that `actual` is within the relative tolerance of `expected`.
+fn assert_close(actual: f64, expected: f64, tolerance: f64, context: &str) {
+    let error = (actual - expected).abs();
+    assert!(
+        error <= tolerance * expected.abs(),
+        "{context}: got {actual}, expected {expected} (relative error {:e})",
+        error / expected.abs()
+    );
+}
+
+#[test]
+fn test_reference_table_covers_every_unit() {
+    for unit in UnitEnum::all() {
+        let listed = REFERENCE.iter().any(|(reference, _, _)| reference == unit)
+            || TEMPERATURE_UNITS.contains(unit)
+            || PRESSURE_UNITS.contains(unit);
+        assert!(listed, "{unit} is missing from the reference table");
+    }
+}
+
+#[test]
+fn test_every_pair_against_reference_table() {
+    for (from, from_si, from_tolerance) in REFERENCE {
+        for (to, to_si, to_tolerance) in REFERENCE.iter().filter(|(to, _, _)| to.dimension() == from.dimension()) {
+            let converted = from.try_convert_to(1.0, to).unwrap();
+            let context = format!("1 {} in {}", from.abbr(), to.abbr());
+            assert_close(converted, from_si / to_si, from_tolerance + to_tolerance, &context);
+        }
+    }
+}
+
+#[test]
+fn test_every_pair_rejects_other_dimensions() {
+    for (from, _, _) in REFERENCE {
+        for to in UnitEnum::all().iter().filter(|to| to.dimension() != from.dimension()) {
+            assert!(from.try_convert_to(1.0, to).is_err(), "{} to {}", from.abbr(), to.abbr());
+        }
+    }
+}
+
+#[test]
+fn test_temperature_points() {
+    for point in TEMPERATURE_POINTS {
+        for (from, value) in TEMPERATURE_UNITS.iter().zip(point) {
+            for (to, expected) in TEMPERATURE_UNITS.iter().zip(point) {
+                let converted = from.try_convert_to(*value, to).unwrap();
+                assert!(
+                    (converted - expected).abs() <= TEMPERATURE_TOLERANCE,
+                    "{value} {} in {}: got {converted}, expected {expected}",
+                    from.abbr(),
+                    to.abbr()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pressure_points() {
+    for point in PRESSURE_POINTS {
+        for (from, value) in PRESSURE_UNITS.iter().zip(point) {
+            for (to, expected) in PRESSURE_UNITS.iter().zip(point) {
+                let converted = from.try_convert_to(*value, to).unwrap();
+                assert!(
+                    (converted - expected).abs() <= PRESSURE_TOLERANCE * expected.abs().max(1.0),
+                    "{value} {} in {}: got {converted}, expected {expected}",
+                    from.abbr(),
+                    to.abbr()
+                );
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------
+
+/// Small xorshift64* generator, so the round trips are random but repeatable.
+struct Rng(u64);
+
+impl Rng {
+    /// Get the next number, uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        let bits = self.0.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11;
+        bits as f64 / (1u64 << 53) as f64
+    }
+
+    /// Get a random magnitude between 1e-9 and 1e12, of either sign
+    fn magnitude(&mut self) -> f64 {
+        let sign = if self.next_f64() < 0.5 { -1.0 } else { 1.0 };
+        sign * 10f64.powf(self.next_f64() * 21.0 - 9.0)
+    }
+}
+
+#[test]
+fn test_random_round_trips_within_one_ulp() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for (from, _, _) in REFERENCE {
+        for (to, _, _) in REFERENCE.iter().filter(|(to, _, _)| to.dimension() == from.dimension()) {
+            for _ in 0..200 {
+                let value = rng.magnitude();
+                let there = from.try_convert_to(value, to).unwrap();
+                let back = to.try_convert_to(there, from).unwrap();
+                assert!(
+                    (back - value).abs() <= f64::EPSILON * value.abs(),
+                    "{value} {} to {} and back gave {back}",
+                    from.abbr(),
+                    to.abbr()
+                );
+            }
+        }
+    }
+}
+
+/// Round trip random values between every pair of `units`, where the values
+/// are `si_values` converted into the unit they start from.
+/// The offsets cancel, so the round trip is only within 4 ulps of the largest
+/// of the value and the two offsets, as `SiConversion::convert` documents.
+fn assert_offset_round_trips(units: &[UnitEnum], mut si_value: impl FnMut() -> f64) {
+    for from in units {
+        for to in units {
+            let (from_conversion, to_conversion) = (from.si_conversion(), to.si_conversion());
+            let to_offset = to_conversion.offset * to_conversion.scale / from_conversion.scale;
+            for _ in 0..200 {
+                let value = from.try_from_si(si_value()).unwrap();
+                let there = from.try_convert_to(value, to).unwrap();
+                let back = to.try_convert_to(there, from).unwrap();
+                let largest = value.abs().max(from_conversion.offset.abs()).max(to_offset.abs());
+                let tolerance = 4.0 * f64::EPSILON * largest;
+                assert!((back - value).abs() <= tolerance, "{value} {from} to {to} and back gave {back}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_random_temperature_round_trips() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    // stay above absolute zero on every scale
+    assert_offset_round_trips(&TEMPERATURE_UNITS, || rng.next_f64() * 10_000.0);
+}
+
+#[test]
+fn test_random_pressure_round_trips() {
+    let mut rng = Rng(0x6a09_e667_f3bc_c909);
+    // from vacuum up to 10 MPa, which covers gauge pressures on either side of the reference
+    assert_offset_round_trips(&PRESSURE_UNITS, || rng.next_f64() * 1e7);
+}